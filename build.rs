@@ -0,0 +1,55 @@
+//! Generates the in-process solution registry when the `registry` feature is enabled.
+//! Every solution in `src/bin/` is compiled into the main binary as a module.
+use std::{env, fs, path::Path};
+
+fn main() {
+    println!("cargo::rerun-if-changed=build.rs");
+    println!("cargo::rerun-if-env-changed=AOC_YEAR");
+
+    if env::var_os("CARGO_FEATURE_REGISTRY").is_none() {
+        return;
+    }
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out_dir = env::var("OUT_DIR").unwrap();
+
+    // NOTE: mirrors the directory layout rules of `template::Year`.
+    let mut bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+    if let Ok(year) = env::var("AOC_YEAR") {
+        let year_dir = bin_dir.join(&year);
+        if year_dir.is_dir() {
+            bin_dir = year_dir;
+        }
+    }
+
+    println!("cargo::rerun-if-changed={}", bin_dir.display());
+
+    let mut days: Vec<(String, String)> = fs::read_dir(&bin_dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let stem = path.file_stem()?.to_str()?;
+            let is_solution = path.extension()? == "rs"
+                && stem.len() == 2
+                && stem.chars().all(|c| c.is_ascii_digit());
+            is_solution.then(|| (stem.to_string(), path.display().to_string()))
+        })
+        .collect();
+
+    days.sort();
+
+    let mut registry = String::new();
+
+    for (day, path) in &days {
+        registry.push_str(&format!("#[path = {path:?}]\nmod day_{day};\n"));
+    }
+
+    registry
+        .push_str("\npub static SOLUTIONS: &[advent_of_code::template::registry::Solution] = &[\n");
+    for (day, _) in &days {
+        registry.push_str(&format!("    day_{day}::SOLUTION,\n"));
+    }
+    registry.push_str("];\n");
+
+    fs::write(Path::new(&out_dir).join("registry.rs"), registry).unwrap();
+}
@@ -19,7 +19,7 @@ fn is_invalid_id_part_one(id: u64) -> bool {
 
     let num_digits = num_digits(id);
 
-    if num_digits < 2 || num_digits % 2 != 0 {
+    if num_digits < 2 || !num_digits.is_multiple_of(2) {
         return false;
     }
 
@@ -68,7 +68,7 @@ fn is_invalid_id_part_two(id: u64) -> bool {
 
     // Try all possible block digit lengths
     for block_digits in 1..=num_digits / 2 {
-        if num_digits % block_digits != 0 {
+        if !num_digits.is_multiple_of(block_digits) {
             continue;
         }
 
@@ -118,19 +118,7 @@ pub fn part_two(input: &str) -> Option<u64> {
     Some(sum_invalid)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(1227775554));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(4174379265));
-    }
+advent_of_code::example_tests! {
+    test_part_one: part_one(example) => Some(1227775554),
+    test_part_two: part_two(example) => Some(4174379265),
 }
@@ -1,4 +1,4 @@
-advent_of_code::solution!(3);
+advent_of_code::solution!(3, params = [digits { default: 12 }]);
 
 use std::cmp::max;
 use std::collections::HashMap;
@@ -91,25 +91,13 @@ pub fn part_two(input: &str) -> Option<u64> {
             continue;
         }
 
-        joltage_sum += find_battery_joltage_part_two(battery, 12);
+        joltage_sum += find_battery_joltage_part_two(battery, advent_of_code::param!(digits));
     }
 
     Some(joltage_sum)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(357));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3121910778619));
-    }
+advent_of_code::example_tests! {
+    test_part_one: part_one(example) => Some(357),
+    test_part_two: part_two(example) => Some(3121910778619),
 }
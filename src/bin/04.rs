@@ -107,19 +107,7 @@ pub fn part_two(input: &str) -> Option<u64> {
     Some(removed_rolls)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(13));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(43));
-    }
+advent_of_code::example_tests! {
+    test_part_one: part_one(example) => Some(13),
+    test_part_two: part_two(example) => Some(43),
 }
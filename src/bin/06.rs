@@ -83,11 +83,11 @@ impl Grid {
 
             if col.iter().all(|&ch| ch == ' ') {
                 // Blank column - end current problem
-                if let Some(op) = cur_op {
-                    if !cur_numbers.is_empty() {
-                        problems.push(Problem { numbers: cur_numbers, op });
-                        cur_numbers = Vec::new();
-                    }
+                if let Some(op) = cur_op
+                    && !cur_numbers.is_empty()
+                {
+                    problems.push(Problem { numbers: cur_numbers, op });
+                    cur_numbers = Vec::new();
                 }
                 cur_op = None;
             } else {
@@ -110,10 +110,10 @@ impl Grid {
         }
 
         // Last problem
-        if let Some(op) = cur_op {
-            if !cur_numbers.is_empty() {
-                problems.push(Problem { numbers: cur_numbers, op });
-            }
+        if let Some(op) = cur_op
+            && !cur_numbers.is_empty()
+        {
+            problems.push(Problem { numbers: cur_numbers, op });
         }
 
         problems.reverse();
@@ -140,19 +140,7 @@ pub fn part_two(input: &str) -> Option<u64> {
     Some(total as u64)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(4277556));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3263827));
-    }
+advent_of_code::example_tests! {
+    test_part_one: part_one(example) => Some(4277556),
+    test_part_two: part_two(example) => Some(3263827),
 }
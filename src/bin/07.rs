@@ -38,6 +38,7 @@ impl<'a> Simulator<'a> {
         Self { grid }
     }
 
+    #[allow(clippy::needless_range_loop)]
     fn run(&self) -> Option<SimulationResult> {
         let (start_row, start_col) = self.grid.find_start()?;
 
@@ -98,19 +99,7 @@ pub fn part_two(input: &str) -> Option<u128> {
     sim.run().map(|res| res.timelines)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(21));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(40));
-    }
+advent_of_code::example_tests! {
+    test_part_one: part_one(example) => Some(21),
+    test_part_two: part_two(example) => Some(40),
 }
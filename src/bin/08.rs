@@ -1,6 +1,6 @@
-advent_of_code::solution!(8);
+advent_of_code::solution!(8, params = [connections { default: 1000, example: 10 }]);
 
-use std::{convert::Infallible, str::FromStr, usize};
+use std::{convert::Infallible, str::FromStr};
 
 #[derive(Debug)]
 struct Point(usize, usize, usize);
@@ -113,7 +113,7 @@ fn solve_with_connections(input: &str, connections: usize) -> Option<u64> {
 }
 
 pub fn part_one(input: &str) -> Option<u64> {
-    solve_with_connections(input, 1000)
+    solve_with_connections(input, advent_of_code::param!(connections))
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
@@ -160,19 +160,7 @@ pub fn part_two(input: &str) -> Option<u64> {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = solve_with_connections(&advent_of_code::template::read_file("examples", DAY), 10);
-        assert_eq!(result, Some(40));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(25272));
-    }
+advent_of_code::example_tests! {
+    test_part_one: part_one(example) => Some(40),
+    test_part_two: part_two(example) => Some(25272),
 }
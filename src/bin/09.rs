@@ -107,7 +107,7 @@ pub fn part_two(input: &str) -> Option<u64> {
             candidates.push(Rect::from_points(&grid.red_tiles[i], &grid.red_tiles[j]));
         }
     }
-    candidates.sort_by(|a, b| b.area().cmp(&a.area()));
+    candidates.sort_by_key(|b| std::cmp::Reverse(b.area()));
 
     let mut lines: Vec<Rect> = Vec::new();
     for i in 0..n {
@@ -129,19 +129,7 @@ pub fn part_two(input: &str) -> Option<u64> {
     None
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(50));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(24));
-    }
+advent_of_code::example_tests! {
+    test_part_one: part_one(example) => Some(50),
+    test_part_two: part_two(example) => Some(24),
 }
@@ -61,6 +61,7 @@ impl Machine {
     }
 }
 
+#[allow(clippy::needless_range_loop)]
 fn min_presses_for_machine_lights(machine: &Machine) -> Option<usize> {
     let n = machine.target.len();
     let m = machine.buttons.len();
@@ -209,7 +210,7 @@ fn min_presses_for_machine_lights(machine: &Machine) -> Option<usize> {
         }
 
         let presses: usize = x.iter().map(|&v| v as usize).sum();
-        if best.map_or(true, |b| presses < b) {
+        if best.is_none_or(|b| presses < b) {
             best = Some(presses);
         }
     }
@@ -235,7 +236,7 @@ fn min_presses_for_machine_jolts(machine: &Machine) -> Option<u32> {
     // Objective: minimize total button presses
     let mut objective = 0.0 * x[0]; // zero Expression
     for &v in &x {
-        objective = objective + v;
+        objective += v;
     }
 
     let mut problem = vars.minimise(objective).using(microlp);
@@ -245,7 +246,7 @@ fn min_presses_for_machine_jolts(machine: &Machine) -> Option<u32> {
         let mut expr = 0.0 * x[0]; // 0 * var => zero expression
         for (j, btn) in machine.buttons.iter().enumerate() {
             if btn.contains(&i) {
-                expr = expr + x[j];
+                expr += x[j];
             }
         }
 
@@ -285,19 +286,7 @@ pub fn part_two(input: &str) -> Option<u64> {
     Some(total)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(7));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(33));
-    }
+advent_of_code::example_tests! {
+    test_part_one: part_one(example) => Some(7),
+    test_part_two: part_two(example) => Some(33),
 }
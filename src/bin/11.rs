@@ -1,4 +1,12 @@
-advent_of_code::solution!(11);
+advent_of_code::solution!(
+    11,
+    params = [
+        start { default: "you" },
+        server { default: "svr" },
+        target { default: "out" },
+        mandatory { default: "dac,fft" },
+    ]
+);
 
 use std::collections::{HashMap, HashSet};
 
@@ -154,30 +162,22 @@ impl<'a> Graph<'a> {
 }
 
 pub fn part_one(input: &str) -> Option<u64> {
+    let start: String = advent_of_code::param!(start);
+    let target: String = advent_of_code::param!(target);
     let graph = Graph::from_input(input);
-    Some(graph.count_paths("you", "out"))
+    Some(graph.count_paths(&start, &target))
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
+    let server: String = advent_of_code::param!(server);
+    let target: String = advent_of_code::param!(target);
+    let mandatory: String = advent_of_code::param!(mandatory);
+    let mandatory: Vec<&str> = mandatory.split(',').collect();
     let graph = Graph::from_input(input);
-    Some(graph.count_paths_with_mandatory("svr", "out", &["dac", "fft"]))
+    Some(graph.count_paths_with_mandatory(&server, &target, &mandatory))
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(5));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
-        ));
-        assert_eq!(result, Some(2));
-    }
+advent_of_code::example_tests! {
+    test_part_one: part_one(example) => Some(5),
+    test_part_two: part_two(example 2) => Some(2),
 }
@@ -9,7 +9,7 @@ struct BitBoard {
 
 impl BitBoard {
     fn new(num_cells: usize) -> Self {
-        let num_words = (num_cells + 63) / 64;
+        let num_words = num_cells.div_ceil(64);
         BitBoard {
             data: vec![0; num_words],
         }
@@ -100,14 +100,13 @@ fn parse_problem(input: &str) -> Problem {
 fn is_region_line(line: &str) -> bool {
     if let Some(colon) = line.find(':') {
         let (left, _) = line.split_at(colon);
-        if let Some((w_str, h_str)) = left.split_once('x') {
-            if !w_str.is_empty()
-                && !h_str.is_empty()
-                && w_str.chars().all(|c| c.is_ascii_digit())
-                && h_str.chars().all(|c| c.is_ascii_digit())
-            {
-                return true;
-            }
+        if let Some((w_str, h_str)) = left.split_once('x')
+            && !w_str.is_empty()
+            && !h_str.is_empty()
+            && w_str.chars().all(|c| c.is_ascii_digit())
+            && h_str.chars().all(|c| c.is_ascii_digit())
+        {
+            return true;
         }
     }
     false
@@ -177,7 +176,7 @@ fn parse_single_shape(rows: &[String]) -> (Vec<(i32, i32)>, usize) {
     (cells, size)
 }
 
-fn normalize_cells(cells: &mut Vec<(i32, i32)>) {
+fn normalize_cells(cells: &mut [(i32, i32)]) {
     let min_x = cells.iter().map(|(x, _)| *x).min().unwrap();
     let min_y = cells.iter().map(|(_, y)| *y).min().unwrap();
     for (x, y) in cells.iter_mut() {
@@ -283,7 +282,6 @@ fn can_fill_region(
     let total_cells = area as u32;
 
     backtrack(
-        width,
         total_cells,
         shape_sizes,
         &placements,
@@ -298,7 +296,7 @@ fn compute_placements(
     shapes: &[Vec<Orientation>],
 ) -> Vec<Vec<BitBoard>> {
     let area = width * height;
-    let num_words = (area + 63) / 64;
+    let num_words = area.div_ceil(64);
 
     let mut placements: Vec<Vec<BitBoard>> = Vec::new();
 
@@ -352,7 +350,6 @@ fn compute_placements(
 }
 
 fn backtrack(
-    width: usize,
     total_cells: u32,
     shape_sizes: &[u8],
     placements: &[Vec<BitBoard>],
@@ -410,14 +407,7 @@ fn backtrack(
         let mut new_board = board.clone();
         new_board.or_inplace(pmask);
 
-        if backtrack(
-            width,
-            total_cells,
-            shape_sizes,
-            placements,
-            remaining,
-            &new_board,
-        ) {
+        if backtrack(total_cells, shape_sizes, placements, remaining, &new_board) {
             return true;
         }
 
@@ -427,19 +417,7 @@ fn backtrack(
     false
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(2));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
-    }
+advent_of_code::example_tests! {
+    test_part_one: part_one(example) => Some(2),
+    test_part_two: part_two(example) => None,
 }
@@ -1,13 +1,24 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{all, download, read, scaffold, solve, time, verify};
 use args::{AppArguments, parse};
 
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
+use advent_of_code::template::{Day, Year};
 #[cfg(feature = "today")]
 use std::process;
 
+/// Every solution compiled into this binary, see `build.rs`.
+#[cfg(feature = "registry")]
+mod registry {
+    include!(concat!(env!("OUT_DIR"), "/registry.rs"));
+}
+
 mod args {
     use advent_of_code::template::Day;
+    use advent_of_code::template::bench::BenchConfig;
+    use advent_of_code::template::commands::time::CompareOptions;
+    use advent_of_code::template::input::InputSource;
+    use advent_of_code::template::limits::Limits;
+    use advent_of_code::template::params::ParamOverride;
     use std::process;
 
     pub enum AppArguments {
@@ -21,42 +32,105 @@ mod args {
             day: Day,
             download: bool,
             overwrite: bool,
+            template: String,
         },
         Solve {
             day: Day,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            format: Option<String>,
+            input: InputSource,
+            params: Vec<ParamOverride>,
+            watch: bool,
+            test: bool,
         },
         All {
             release: bool,
+            jobs: usize,
+            limits: Limits,
+            params: Vec<ParamOverride>,
         },
         Time {
             all: bool,
             day: Option<Day>,
             store: bool,
+            dhat: bool,
+            bench_args: Vec<String>,
+            compare: Option<CompareOptions>,
+            limits: Limits,
+            params: Vec<ParamOverride>,
+        },
+        History {
+            day: Day,
+        },
+        Verify {
+            day: Option<Day>,
+            release: bool,
+            params: Vec<ParamOverride>,
         },
         #[cfg(feature = "today")]
         Today,
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
-        let mut args = pico_args::Arguments::from_env();
+        // NOTE: `--example` takes an optional value, which pico-args does not support.
+        let (raw_args, mut example) = take_example(std::env::args_os().skip(1).collect());
+        let mut args = pico_args::Arguments::from_vec(raw_args);
 
         let app_args = match args.subcommand()?.as_deref() {
             Some("all") => AppArguments::All {
                 release: args.contains("--release"),
+                jobs: args.opt_value_from_str("--jobs")?.unwrap_or(1),
+                limits: parse_limits(&mut args)?,
+                params: args.values_from_str("--param")?,
+            },
+            Some("time") if args.contains("--history") => AppArguments::History {
+                day: args.free_from_str()?,
             },
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let dhat = args.contains("--dhat");
+
+                // benchmark configuration is forwarded to the solutions as-is,
+                // values are parsed with the types of `BenchConfig` so invalid values fail here.
+                let mut bench_args = vec![];
+                for flag in ["--warmup", "--min-samples", "--max-samples"] {
+                    if let Some(value) = args.opt_value_from_str::<_, u32>(flag)? {
+                        bench_args.extend([flag.to_string(), value.to_string()]);
+                    }
+                }
+                if let Some(value) = args.opt_value_from_str::<_, u64>("--bench-time")? {
+                    bench_args.extend(["--bench-time".to_string(), value.to_string()]);
+                }
+                BenchConfig::parse(&bench_args);
+
+                let compare = if args.contains("--compare") {
+                    Some(CompareOptions {
+                        threshold: args.opt_value_from_str("--threshold")?.unwrap_or(10.0),
+                        noise: args.opt_value_from_str("--noise")?.unwrap_or(3.0),
+                    })
+                } else {
+                    None
+                };
 
                 AppArguments::Time {
                     all,
+                    limits: parse_limits(&mut args)?,
+                    params: args.values_from_str("--param")?,
                     day: args.opt_free_from_str()?,
                     store,
+                    dhat,
+                    bench_args,
+                    compare,
                 }
             }
+            Some("verify") => AppArguments::Verify {
+                release: args.contains("--release"),
+                params: args.values_from_str("--param")?,
+                day: args.opt_free_from_str()?,
+            },
             Some("download") => AppArguments::Download {
                 day: args.free_from_str()?,
             },
@@ -67,12 +141,20 @@ mod args {
                 day: args.free_from_str()?,
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
+                template: args
+                    .opt_value_from_str("--template")?
+                    .unwrap_or_else(|| "default".into()),
             },
             Some("solve") => AppArguments::Solve {
                 day: args.free_from_str()?,
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
                 dhat: args.contains("--dhat"),
+                format: args.opt_value_from_str("--format")?,
+                input: parse_input(&mut args, example.take())?,
+                params: args.values_from_str("--param")?,
+                watch: args.contains("--watch"),
+                test: args.contains("--test"),
             },
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
@@ -86,54 +168,144 @@ mod args {
             }
         };
 
-        let remaining = args.finish();
+        let mut remaining = args.finish();
+        if example.is_some() {
+            remaining.push("--example".into());
+        }
         if !remaining.is_empty() {
             eprintln!("Warning: unknown argument(s): {remaining:?}.");
         }
 
         Ok(app_args)
     }
+
+    /// Remove `--example [N]` from the raw arguments. Returns `Some(None)` if the flag is passed without a number.
+    fn take_example(
+        mut args: Vec<std::ffi::OsString>,
+    ) -> (Vec<std::ffi::OsString>, Option<Option<u8>>) {
+        let Some(index) = args.iter().position(|x| x == "--example") else {
+            return (args, None);
+        };
+
+        args.remove(index);
+
+        let number = args
+            .get(index)
+            .and_then(|x| x.to_str())
+            .and_then(|x| x.parse().ok());
+
+        if number.is_some() {
+            args.remove(index);
+        }
+
+        (args, Some(number))
+    }
+
+    /// Parse the mutually exclusive `--member <NAME>`, `--input <PATH>`, `--stdin` and `--example [N]` options.
+    fn parse_input(
+        args: &mut pico_args::Arguments,
+        example: Option<Option<u8>>,
+    ) -> Result<InputSource, Box<dyn std::error::Error>> {
+        let member: Option<String> = args.opt_value_from_str("--member")?;
+        let file: Option<String> = args.opt_value_from_str("--input")?;
+        let stdin = args.contains("--stdin");
+
+        match (member, file, stdin, example) {
+            (None, None, false, None) => Ok(InputSource::Puzzle),
+            (Some(member), None, false, None) => Ok(InputSource::Member(member)),
+            (None, Some(path), false, None) => Ok(InputSource::File(path)),
+            (None, None, true, None) => Ok(InputSource::Stdin),
+            (None, None, false, Some(number)) => Ok(InputSource::Example(number)),
+            _ => Err(
+                "only one of `--member`, `--input`, `--stdin` and `--example` can be passed."
+                    .into(),
+            ),
+        }
+    }
+
+    /// Parse the repeatable `--timeout [DAY[.PART]=]SECS` and `--memory-limit [DAY[.PART]=]MB` options.
+    fn parse_limits(args: &mut pico_args::Arguments) -> Result<Limits, pico_args::Error> {
+        Ok(Limits {
+            timeouts: args.values_from_str("--timeout")?,
+            memory_limits: args.values_from_str("--memory-limit")?,
+        })
+    }
 }
 
 fn main() {
+    #[cfg(feature = "registry")]
+    advent_of_code::template::registry::install(registry::SOLUTIONS);
+
     match parse() {
         Err(err) => {
             eprintln!("Error: {err}");
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
+            AppArguments::All {
+                release,
+                jobs,
+                limits,
+                params,
+            } => all::handle(release, jobs, &limits, &params),
+            AppArguments::Time {
+                day,
+                all,
+                store,
+                dhat,
+                bench_args,
+                compare,
+                limits,
+                params,
+            } => time::handle(
+                day,
+                all,
+                store,
+                dhat,
+                &bench_args,
+                compare,
+                &limits,
+                &params,
+            ),
+            AppArguments::History { day } => time::handle_history(day),
+            AppArguments::Verify {
+                day,
+                release,
+                params,
+            } => verify::handle(day, release, &params),
             AppArguments::Download { day } => download::handle(day),
             AppArguments::Read { day } => read::handle(day),
             AppArguments::Scaffold {
                 day,
                 download,
                 overwrite,
-            } => {
-                scaffold::handle(day, overwrite);
-                if download {
-                    download::handle(day);
-                }
-            }
+                template,
+            } => scaffold::handle(day, overwrite, download, &template),
             AppArguments::Solve {
                 day,
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                format,
+                input,
+                params,
+                watch,
+                test,
+            } => solve::handle(
+                day, release, dhat, submit, format, &input, &params, watch, test,
+            ),
             #[cfg(feature = "today")]
             AppArguments::Today => {
                 match Day::today() {
                     Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
+                        scaffold::handle(day, false, true, "default");
                         read::handle(day)
                     }
                     None => {
                         eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
+                            "`today` command can only be run during the {} event. \
+                            Please use `scaffold` with a specific day.",
+                            Year::current()
                         );
                         process::exit(1)
                     }
@@ -0,0 +1,207 @@
+/// The answer to a puzzle part, as returned by solutions.
+/// Solutions can return any type that converts into an [`Answer`]: integers of any width, strings or an [`Answer`] itself.
+use std::{convert::Infallible, error::Error, fmt::Display, str::FromStr};
+
+use crate::template::ocr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    /// A signed integer.
+    Int(i128),
+    /// An unsigned integer. Unlike [`Answer::Int`], this can hold any `u128`.
+    UInt(u128),
+    /// A single line of text.
+    Text(String),
+    /// A multi-line grid of ASCII art, e.g. letters drawn with `#` and `.`.
+    Art(String),
+}
+
+impl Answer {
+    /// Whether the answer is ASCII art spanning multiple lines.
+    pub fn is_art(&self) -> bool {
+        matches!(self, Answer::Art(_))
+    }
+
+    /// The value to submit to the website and to compare against recorded answers.
+    /// The letters of ASCII art are recognized with [`ocr::recognize`]. Returns an error if that fails.
+    pub fn submission(&self) -> Result<String, String> {
+        match self {
+            Answer::Int(x) => Ok(x.to_string()),
+            Answer::UInt(x) => Ok(x.to_string()),
+            Answer::Text(x) => Ok(x.clone()),
+            Answer::Art(art) => ocr::recognize(art).ok_or_else(|| {
+                "could not recognize the letters of the ASCII art, return them as a string instead."
+                    .into()
+            }),
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Int(x) => write!(f, "{x}"),
+            Answer::UInt(x) => write!(f, "{x}"),
+            Answer::Text(x) | Answer::Art(x) => write!(f, "{x}"),
+        }
+    }
+}
+
+/// Parses answers printed by a solution, e.g. the answers of a part report.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(if let Ok(x) = s.parse::<u128>() {
+            Answer::UInt(x)
+        } else if let Ok(x) = s.parse::<i128>() {
+            Answer::Int(x)
+        } else {
+            Answer::from(s)
+        })
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        if value.trim().contains('\n') {
+            Answer::Art(value)
+        } else {
+            Answer::Text(value)
+        }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::from(value.to_string())
+    }
+}
+
+impl From<char> for Answer {
+    fn from(value: char) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+macro_rules! impl_from_int {
+    ($variant:ident, $as:ty, $( $int:ty ),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(value: $int) -> Self {
+                    Answer::$variant(value as $as)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(Int, i128, i8, i16, i32, i64, i128, isize);
+impl_from_int!(UInt, u128, u8, u16, u32, u64, u128, usize);
+
+/* -------------------------------------------------------------------------- */
+
+/// Return types of solution parts:
+///  - `Option<T>`: `None` means that the part is not implemented yet.
+///  - `Result<T, E>`: `Err` means that the part failed. `E` can be any error type, a `String` or a `&str`.
+pub trait IntoAnswer {
+    /// Returns the answer, `None` if the part is not implemented, or the error chain of a failed part.
+    fn into_answer(self) -> Result<Option<Answer>, String>;
+}
+
+impl<T: Into<Answer>> IntoAnswer for Option<T> {
+    fn into_answer(self) -> Result<Option<Answer>, String> {
+        Ok(self.map(Into::into))
+    }
+}
+
+impl<T: Into<Answer>, E: Into<Box<dyn Error>>> IntoAnswer for Result<T, E> {
+    fn into_answer(self) -> Result<Option<Answer>, String> {
+        self.map(|x| Some(x.into()))
+            .map_err(|e| error_chain(e.into().as_ref()))
+    }
+}
+
+/// Format an error followed by its sources, one per line.
+fn error_chain(error: &dyn Error) -> String {
+    let mut chain = error.to_string();
+    let mut source = error.source();
+
+    while let Some(e) = source {
+        chain.push_str(&format!("\n  caused by: {e}"));
+        source = e.source();
+    }
+
+    chain
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::num::ParseIntError;
+
+    use super::{Answer, IntoAnswer};
+
+    #[test]
+    fn converts_values() {
+        assert_eq!(Answer::from(42_u64), Answer::UInt(42));
+        assert_eq!(Answer::from(u128::MAX), Answer::UInt(u128::MAX));
+        assert_eq!(Answer::from(-3_i32), Answer::Int(-3));
+        assert_eq!(Answer::from("abc"), Answer::Text("abc".into()));
+        assert_eq!(Answer::from("#.\n.#\n"), Answer::Art("#.\n.#\n".into()));
+    }
+
+    #[test]
+    fn parses_printed_answers() {
+        assert_eq!("42".parse(), Ok(Answer::UInt(42)));
+        assert_eq!("-42".parse(), Ok(Answer::Int(-42)));
+        assert_eq!("1,2,3".parse(), Ok(Answer::Text("1,2,3".into())));
+        assert_eq!("#.\n.#".parse(), Ok(Answer::Art("#.\n.#".into())));
+    }
+
+    #[test]
+    fn submits_recognized_art() {
+        assert_eq!(Answer::from(-1_i64).submission(), Ok("-1".into()));
+        assert_eq!(
+            Answer::from("#..#\n#..#\n####\n#..#\n#..#\n#..#").submission(),
+            Ok("H".into())
+        );
+        assert!(Answer::from("#.\n.#").submission().is_err());
+    }
+
+    #[test]
+    fn converts_part_results() {
+        assert_eq!(Some(1_u8).into_answer(), Ok(Some(Answer::UInt(1))));
+        assert_eq!(None::<u8>.into_answer(), Ok(None));
+        assert_eq!(
+            "x".parse::<u64>().into_answer(),
+            Err("invalid digit found in string".into())
+        );
+        assert_eq!(Err::<u64, _>("no '['").into_answer(), Err("no '['".into()));
+    }
+
+    #[test]
+    fn formats_error_chains() {
+        #[derive(Debug)]
+        struct ParseError(ParseIntError);
+
+        impl std::fmt::Display for ParseError {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "invalid point")
+            }
+        }
+
+        impl std::error::Error for ParseError {
+            fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+                Some(&self.0)
+            }
+        }
+
+        let result: Result<u64, _> = "x".parse::<u64>().map_err(ParseError);
+        assert_eq!(
+            result.into_answer(),
+            Err("invalid point\n  caused by: invalid digit found in string".into())
+        );
+    }
+}
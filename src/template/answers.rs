@@ -0,0 +1,137 @@
+/// Module that keeps the answers that were accepted by the website.
+/// Every answer lives in its own file, e.g. `data/answers/01-1.txt` for part one of day one.
+/// The answers of team members live in a directory per day, e.g. `data/answers/01/alice-1.txt`.
+use std::{fs, io, path::Path};
+
+use crate::template::{Day, Year, input::InputSource, report::PartReport};
+
+/// Result of checking an answer against the recorded answer of a part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    /// The answer matches the recorded answer.
+    Correct,
+    /// The answer differs from the recorded answer, or the part did not produce one.
+    Incorrect { expected: String },
+    /// There is no recorded answer for the part.
+    Unknown,
+}
+
+fn get_answer_path(day: Day, part: u8) -> String {
+    format!("{}/answers/{day}-{part}.txt", Year::current().data_dir())
+}
+
+fn get_member_answer_path(day: Day, member: &str, part: u8) -> String {
+    format!(
+        "{}/answers/{day}/{member}-{part}.txt",
+        Year::current().data_dir()
+    )
+}
+
+fn read_path(path: &str) -> Option<String> {
+    let answer = fs::read_to_string(path).ok()?;
+    let trimmed = answer.trim();
+    (!trimmed.is_empty()).then(|| trimmed.to_string())
+}
+
+/// Read the recorded answer of a part, if any.
+pub fn read(day: Day, part: u8) -> Option<String> {
+    read_path(&get_answer_path(day, part))
+}
+
+/// Read the recorded answer of a team member for a part, if any.
+pub fn read_member(day: Day, member: &str, part: u8) -> Option<String> {
+    read_path(&get_member_answer_path(day, member, part))
+}
+
+fn write_path(path: &str, answer: &str) -> Result<(), io::Error> {
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    fs::write(path, format!("{}\n", answer.trim()))
+}
+
+/// Record the answer of a part, replacing any previously recorded answer.
+pub fn store(day: Day, part: u8, answer: &str) -> Result<(), io::Error> {
+    write_path(&get_answer_path(day, part), answer)
+}
+
+/// Record the answer of a team member for a part, replacing any previously recorded answer.
+pub fn store_member(day: Day, member: &str, part: u8, answer: &str) -> Result<(), io::Error> {
+    write_path(&get_member_answer_path(day, member, part), answer)
+}
+
+/// Whether an answer has been recorded for any part of `day`.
+pub fn has_answers(day: Day) -> bool {
+    [1, 2].iter().any(|part| read(day, *part).is_some())
+}
+
+/// Compare an answer against the recorded answer of its part.
+pub fn verdict(expected: Option<&str>, answer: Option<&str>) -> Verdict {
+    match (expected, answer) {
+        (None, _) => Verdict::Unknown,
+        (Some(expected), Some(answer)) if expected == answer.trim() => Verdict::Correct,
+        (Some(expected), _) => Verdict::Incorrect {
+            expected: expected.to_string(),
+        },
+    }
+}
+
+/// Compare the answer of a report against the recorded answer of its part.
+pub fn check(report: &PartReport) -> Verdict {
+    verdict(
+        read(report.day, report.part).as_deref(),
+        report.answer.as_deref(),
+    )
+}
+
+/// Compare the answer of a report against the recorded answer for the input it ran against.
+/// Only the puzzle input and the inputs of team members have recorded answers.
+pub fn check_input(report: &PartReport, source: &InputSource) -> Verdict {
+    let expected = match source {
+        InputSource::Puzzle => read(report.day, report.part),
+        InputSource::Member(member) => read_member(report.day, member, report.part),
+        _ => None,
+    };
+
+    verdict(expected.as_deref(), report.answer.as_deref())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Verdict, verdict};
+
+    #[test]
+    fn accepts_matching_answers() {
+        assert_eq!(verdict(Some("42"), Some("42")), Verdict::Correct);
+        assert_eq!(verdict(Some("42"), Some("42\n")), Verdict::Correct);
+    }
+
+    #[test]
+    fn flags_differing_answers() {
+        assert_eq!(
+            verdict(Some("42"), Some("43")),
+            Verdict::Incorrect {
+                expected: "42".into()
+            }
+        );
+    }
+
+    #[test]
+    fn flags_unsolved_parts() {
+        assert_eq!(
+            verdict(Some("42"), None),
+            Verdict::Incorrect {
+                expected: "42".into()
+            }
+        );
+    }
+
+    #[test]
+    fn ignores_parts_without_recorded_answers() {
+        assert_eq!(verdict(None, Some("42")), Verdict::Unknown);
+        assert_eq!(verdict(None, None), Verdict::Unknown);
+    }
+}
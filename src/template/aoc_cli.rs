@@ -2,15 +2,19 @@
 use std::{
     fmt::Display,
     process::{Command, Output, Stdio},
+    str::FromStr,
+    time::Duration,
 };
 
-use crate::template::Day;
+use crate::template::{ANSI_BOLD, ANSI_GREEN, ANSI_RED, ANSI_RESET, Day, Year};
 
 #[derive(Debug)]
 pub enum AocCommandError {
     CommandNotFound,
     CommandNotCallable,
     BadExitStatus(Output),
+    /// aoc-cli printed a response to a submission that could not be understood.
+    UnexpectedResponse(String),
 }
 
 impl Display for AocCommandError {
@@ -21,10 +25,131 @@ impl Display for AocCommandError {
             AocCommandError::BadExitStatus(_) => {
                 write!(f, "aoc-cli exited with a non-zero status.")
             }
+            AocCommandError::UnexpectedResponse(response) => {
+                write!(f, "aoc-cli responded unexpectedly: {}", response.trim())
+            }
         }
     }
 }
 
+/// Verdict of the website on a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubmissionOutcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// An answer was submitted too recently. Contains the remaining wait time, if it was reported.
+    RateLimited {
+        wait: Option<Duration>,
+    },
+    /// The part has already been solved, so the answer was not judged.
+    AlreadySolved,
+}
+
+impl SubmissionOutcome {
+    /// Read the outcome from the response that aoc-cli printed for a submission.
+    pub fn parse(response: &str) -> Option<Self> {
+        if response.contains("That's the right answer") {
+            Some(Self::Correct)
+        } else if response.contains("your answer is too high") {
+            Some(Self::TooHigh)
+        } else if response.contains("your answer is too low") {
+            Some(Self::TooLow)
+        } else if response.contains("That's not the right answer") {
+            Some(Self::Wrong)
+        } else if response.contains("You gave an answer too recently") {
+            Some(Self::RateLimited {
+                wait: parse_wait(response),
+            })
+        } else if response.contains("Did you already complete it?") {
+            Some(Self::AlreadySolved)
+        } else {
+            None
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Correct => "correct",
+            Self::TooHigh => "too_high",
+            Self::TooLow => "too_low",
+            Self::Wrong => "wrong",
+            Self::RateLimited { .. } => "rate_limited",
+            Self::AlreadySolved => "already_solved",
+        }
+    }
+
+    /// Whether the answer was judged and found to be wrong.
+    pub fn is_rejection(self) -> bool {
+        matches!(self, Self::TooHigh | Self::TooLow | Self::Wrong)
+    }
+
+    /// Describe the outcome for printing to the command-line.
+    pub fn message(self) -> String {
+        match self {
+            Self::Correct => format!("{ANSI_GREEN}{ANSI_BOLD}That's the right answer!{ANSI_RESET}"),
+            Self::TooHigh => {
+                format!("{ANSI_RED}That's not the right answer, it is too high.{ANSI_RESET}")
+            }
+            Self::TooLow => {
+                format!("{ANSI_RED}That's not the right answer, it is too low.{ANSI_RESET}")
+            }
+            Self::Wrong => format!("{ANSI_RED}That's not the right answer.{ANSI_RESET}"),
+            Self::RateLimited { wait: Some(wait) } => {
+                format!("You submitted an answer too recently, try again in {wait:?}.")
+            }
+            Self::RateLimited { wait: None } => {
+                "You submitted an answer too recently, try again later.".into()
+            }
+            Self::AlreadySolved => "This part has already been solved.".into(),
+        }
+    }
+}
+
+impl Display for SubmissionOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for SubmissionOutcome {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Self::Correct),
+            "too_high" => Ok(Self::TooHigh),
+            "too_low" => Ok(Self::TooLow),
+            "wrong" => Ok(Self::Wrong),
+            "rate_limited" => Ok(Self::RateLimited { wait: None }),
+            "already_solved" => Ok(Self::AlreadySolved),
+            _ => Err(format!("Unknown submission outcome \"{s}\".")),
+        }
+    }
+}
+
+/// Read the wait time from a rate-limit response, e.g. "You have 1m 25s left to wait."
+fn parse_wait(response: &str) -> Option<Duration> {
+    let start = response.find("You have ")? + "You have ".len();
+    let end = start + response[start..].find(" left to wait")?;
+
+    response[start..end]
+        .split_whitespace()
+        .map(|token| {
+            let (value, unit) = token.split_at(token.len().checked_sub(1)?);
+            let value: u64 = value.parse().ok()?;
+            match unit {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
 pub fn check() -> Result<(), AocCommandError> {
     Command::new("aoc")
         .arg("-V")
@@ -72,36 +197,46 @@ pub fn download(day: Day) -> Result<Output, AocCommandError> {
     Ok(output)
 }
 
-pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandError> {
+/// Submit an answer and print the outcome.
+pub fn submit(day: Day, part: u8, result: &str) -> Result<SubmissionOutcome, AocCommandError> {
     // workaround: the argument order is inverted for submit.
     let mut args = build_args("submit", &[], day);
     args.push(part.to_string());
     args.push(result.to_string());
-    call_aoc_cli(&args)
-}
 
-fn get_input_path(day: Day) -> String {
-    format!("data/inputs/{day}.txt")
+    // capture the response, so it can be parsed into an outcome.
+    let output = Command::new("aoc")
+        .args(&args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|_| AocCommandError::CommandNotCallable)?;
+
+    let response = String::from_utf8_lossy(&output.stdout);
+
+    match SubmissionOutcome::parse(&response) {
+        Some(outcome) => {
+            println!("{}", outcome.message());
+            Ok(outcome)
+        }
+        None if !output.status.success() => Err(AocCommandError::BadExitStatus(output)),
+        None => Err(AocCommandError::UnexpectedResponse(response.into_owned())),
+    }
 }
 
-fn get_puzzle_path(day: Day) -> String {
-    format!("data/puzzles/{day}.md")
+pub(crate) fn get_input_path(day: Day) -> String {
+    format!("{}/inputs/{day}.txt", Year::current().data_dir())
 }
 
-fn get_year() -> Option<u16> {
-    match std::env::var("AOC_YEAR") {
-        Ok(x) => x.parse().ok().or(None),
-        Err(_) => None,
-    }
+pub(crate) fn get_puzzle_path(day: Day) -> String {
+    format!("{}/puzzles/{day}.md", Year::current().data_dir())
 }
 
 fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
     let mut cmd_args = args.to_vec();
 
-    if let Some(year) = get_year() {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
-    }
+    cmd_args.push("--year".into());
+    cmd_args.push(Year::current().to_string());
 
     cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
 
@@ -123,3 +258,69 @@ fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
         Err(AocCommandError::BadExitStatus(output))
     }
 }
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::SubmissionOutcome;
+
+    #[test]
+    fn parses_outcomes() {
+        assert_eq!(
+            SubmissionOutcome::parse("That's the right answer! You are one gold star closer."),
+            Some(SubmissionOutcome::Correct)
+        );
+        assert_eq!(
+            SubmissionOutcome::parse("That's not the right answer; your answer is too high."),
+            Some(SubmissionOutcome::TooHigh)
+        );
+        assert_eq!(
+            SubmissionOutcome::parse("That's not the right answer; your answer is too low."),
+            Some(SubmissionOutcome::TooLow)
+        );
+        assert_eq!(
+            SubmissionOutcome::parse("That's not the right answer. Please wait one minute."),
+            Some(SubmissionOutcome::Wrong)
+        );
+        assert_eq!(
+            SubmissionOutcome::parse(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ),
+            Some(SubmissionOutcome::AlreadySolved)
+        );
+        assert_eq!(SubmissionOutcome::parse("Something went wrong."), None);
+    }
+
+    #[test]
+    fn parses_rate_limits() {
+        assert_eq!(
+            SubmissionOutcome::parse(
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 25s left to wait."
+            ),
+            Some(SubmissionOutcome::RateLimited {
+                wait: Some(Duration::from_secs(85))
+            })
+        );
+        assert_eq!(
+            SubmissionOutcome::parse("You gave an answer too recently."),
+            Some(SubmissionOutcome::RateLimited { wait: None })
+        );
+    }
+
+    #[test]
+    fn roundtrips_strings() {
+        for outcome in [
+            SubmissionOutcome::Correct,
+            SubmissionOutcome::TooHigh,
+            SubmissionOutcome::TooLow,
+            SubmissionOutcome::Wrong,
+            SubmissionOutcome::RateLimited { wait: None },
+            SubmissionOutcome::AlreadySolved,
+        ] {
+            assert_eq!(outcome.as_str().parse(), Ok(outcome));
+        }
+    }
+}
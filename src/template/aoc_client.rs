@@ -0,0 +1,384 @@
+/// Built-in HTTP client for the Advent of Code website.
+/// Responses that don't change are cached on disk, so repeated downloads don't hit the website.
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::template::aoc_cli::SubmissionOutcome;
+use crate::template::markdown::{extract_articles, strip_tags};
+use crate::template::{Day, Year};
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const DEFAULT_CACHE_DIR: &str = ".aoc_cache";
+const USER_AGENT: &str =
+    "advent-of-code-rust template (+https://github.com/fspoettel/advent-of-code-rust)";
+
+#[derive(Debug)]
+pub enum ClientError {
+    SessionNotFound,
+    /// The website could not be reached, so no request was sent.
+    Connect(String),
+    Http(String),
+    IO(io::Error),
+    /// The website responded with a page that could not be understood.
+    UnexpectedResponse(String),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::SessionNotFound => write!(
+                f,
+                "session cookie not found. Set \"ADVENT_OF_CODE_SESSION\" or create \"~/.adventofcode.session\"."
+            ),
+            ClientError::Connect(e) => write!(f, "could not reach the website: {e}"),
+            ClientError::Http(e) => write!(f, "request failed: {e}"),
+            ClientError::IO(e) => write!(f, "could not access cache: {e}"),
+            ClientError::UnexpectedResponse(response) => {
+                write!(f, "unexpected response: {}", response.trim())
+            }
+        }
+    }
+}
+
+impl From<ureq::Error> for ClientError {
+    fn from(e: ureq::Error) -> Self {
+        let is_connect = match &e {
+            ureq::Error::HostNotFound
+            | ureq::Error::ConnectionFailed
+            | ureq::Error::Timeout(ureq::Timeout::Resolve | ureq::Timeout::Connect) => true,
+            ureq::Error::Io(e) => matches!(
+                e.kind(),
+                io::ErrorKind::ConnectionRefused
+                    | io::ErrorKind::HostUnreachable
+                    | io::ErrorKind::NetworkUnreachable
+            ),
+            _ => false,
+        };
+
+        if is_connect {
+            ClientError::Connect(e.to_string())
+        } else {
+            ClientError::Http(e.to_string())
+        }
+    }
+}
+
+impl From<io::Error> for ClientError {
+    fn from(e: io::Error) -> Self {
+        ClientError::IO(e)
+    }
+}
+
+pub struct Client {
+    base_url: String,
+    session: String,
+    cache_dir: Option<PathBuf>,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    /// Create a client. Responses are not cached if no `cache_dir` is passed.
+    pub fn new(base_url: &str, session: &str, cache_dir: Option<PathBuf>) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            cache_dir,
+            agent: ureq::Agent::new_with_defaults(),
+        }
+    }
+
+    /// Create a client configured by the environment:
+    ///  1. the session cookie is read from the same locations as aoc-cli, see [`find_session`].
+    ///  2. `AOC_BASE_URL` overrides the website, e.g. to test against a local stand-in server.
+    ///  3. `AOC_CACHE_DIR` overrides the cache directory (default: `.aoc_cache`).
+    pub fn from_env() -> Result<Self, ClientError> {
+        let session = find_session().ok_or(ClientError::SessionNotFound)?;
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        Ok(Self::new(&base_url, &session, Some(cache_dir())))
+    }
+
+    /// Fetch the puzzle input of a day.
+    pub fn input(&self, year: Year, day: Day) -> Result<String, ClientError> {
+        self.cached(year, day, "input.txt", || {
+            self.get(&format!("/{year}/day/{}/input", day.into_inner()))
+        })
+    }
+
+    /// Fetch the puzzle page of a day. Render it with [`crate::template::markdown::render`].
+    /// Solving part one adds part two to the page, so the page is always fetched.
+    /// The cached page is only used if the website can not be reached, e.g. without network access.
+    pub fn puzzle(&self, year: Year, day: Day) -> Result<String, ClientError> {
+        let path = self.cache_path(year, day, "puzzle.html");
+
+        let page = match self.get(&format!("/{year}/day/{}", day.into_inner())) {
+            Ok(page) => page,
+            Err(e @ (ClientError::Connect(_) | ClientError::Http(_))) => {
+                return path.and_then(|p| fs::read_to_string(p).ok()).ok_or(e);
+            }
+            Err(e) => return Err(e),
+        };
+
+        if extract_articles(&page).is_empty() {
+            return Err(ClientError::UnexpectedResponse(page));
+        }
+
+        store(path, &page)?;
+        Ok(page)
+    }
+
+    /// Submit an answer for a part.
+    pub fn submit(
+        &self,
+        year: Year,
+        day: Day,
+        part: u8,
+        answer: &str,
+    ) -> Result<SubmissionOutcome, ClientError> {
+        let url = format!("{}/{year}/day/{}/answer", self.base_url, day.into_inner());
+
+        let page = self
+            .agent
+            .post(&url)
+            .header("Cookie", &format!("session={}", self.session))
+            .header("User-Agent", USER_AGENT)
+            .send_form([("level", part.to_string()), ("answer", answer.to_string())])?
+            .body_mut()
+            .read_to_string()?;
+
+        let response = extract_articles(&page)
+            .iter()
+            .map(|article| strip_tags(article))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        SubmissionOutcome::parse(&response).ok_or(ClientError::UnexpectedResponse(response))
+    }
+
+    fn get(&self, path: &str) -> Result<String, ClientError> {
+        Ok(self
+            .agent
+            .get(&format!("{}{path}", self.base_url))
+            .header("Cookie", &format!("session={}", self.session))
+            .header("User-Agent", USER_AGENT)
+            .call()?
+            .body_mut()
+            .read_to_string()?)
+    }
+
+    fn cache_path(&self, year: Year, day: Day, name: &str) -> Option<PathBuf> {
+        Some(cache_path(self.cache_dir.as_ref()?, year, day, name))
+    }
+
+    fn cached(
+        &self,
+        year: Year,
+        day: Day,
+        name: &str,
+        fetch: impl FnOnce() -> Result<String, ClientError>,
+    ) -> Result<String, ClientError> {
+        let path = self.cache_path(year, day, name);
+
+        if let Some(content) = path.as_ref().and_then(|p| fs::read_to_string(p).ok()) {
+            return Ok(content);
+        }
+
+        let content = fetch()?;
+        store(path, &content)?;
+        Ok(content)
+    }
+}
+
+/// Write a response to the cache, if caching is enabled.
+fn store(path: Option<PathBuf>, content: &str) -> Result<(), ClientError> {
+    if let Some(path) = path {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, content)?;
+    }
+    Ok(())
+}
+
+/// The cache directory configured by `AOC_CACHE_DIR` (default: `.aoc_cache`).
+pub fn cache_dir() -> PathBuf {
+    env::var("AOC_CACHE_DIR")
+        .unwrap_or_else(|_| DEFAULT_CACHE_DIR.into())
+        .into()
+}
+
+fn cache_path(dir: &Path, year: Year, day: Day, name: &str) -> PathBuf {
+    dir.join(year.to_string()).join(day.to_string()).join(name)
+}
+
+/// Read the cached puzzle page of a day without sending a request.
+/// The page may be outdated, prefer [`Client::puzzle`] when the website can be reached.
+pub fn cached_puzzle(year: Year, day: Day) -> Option<String> {
+    fs::read_to_string(cache_path(&cache_dir(), year, day, "puzzle.html")).ok()
+}
+
+/// Find the session cookie. The locations are checked in the same order as aoc-cli does:
+///  1. the `ADVENT_OF_CODE_SESSION` environment variable.
+///  2. `~/.adventofcode.session`.
+///  3. `adventofcode.session` in the user's config directory.
+pub fn find_session() -> Option<String> {
+    if let Ok(session) = env::var("ADVENT_OF_CODE_SESSION")
+        && !session.trim().is_empty()
+    {
+        return Some(session.trim().to_string());
+    }
+
+    let home = env::var("HOME").or_else(|_| env::var("USERPROFILE")).ok();
+
+    let config_dir = env::var("XDG_CONFIG_HOME")
+        .or_else(|_| env::var("APPDATA"))
+        .ok()
+        .or_else(|| home.as_ref().map(|home| format!("{home}/.config")));
+
+    [
+        home.map(|home| Path::new(&home).join(".adventofcode.session")),
+        config_dir.map(|dir| Path::new(&dir).join("adventofcode.session")),
+    ]
+    .into_iter()
+    .flatten()
+    .find_map(|path| {
+        let session = fs::read_to_string(path).ok()?;
+        let trimmed = session.trim();
+        (!trimmed.is_empty()).then(|| trimmed.to_string())
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        env, fs,
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        process, thread,
+    };
+
+    use super::{Client, ClientError};
+    use crate::{
+        day,
+        template::{Year, aoc_cli::SubmissionOutcome},
+    };
+
+    /// Serve a single request with a fixed body and return the base URL and the request line.
+    fn serve_once(body: &'static str) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(value) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                    content_length = value.trim().parse().unwrap();
+                }
+                if line.trim().is_empty() {
+                    break;
+                }
+            }
+            let mut request_body = vec![0; content_length];
+            reader.read_exact(&mut request_body).unwrap();
+
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+
+            request_line
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn fetches_inputs() {
+        let (base_url, server) = serve_once("1\n2\n3\n");
+        let client = Client::new(&base_url, "secret", None);
+
+        let input = client.input(Year::new(2024).unwrap(), day!(1)).unwrap();
+        assert_eq!(input, "1\n2\n3\n");
+        assert_eq!(
+            server.join().unwrap().trim(),
+            "GET /2024/day/1/input HTTP/1.1"
+        );
+    }
+
+    #[test]
+    fn refreshes_cached_puzzles() {
+        let cache_dir = env::temp_dir().join(format!("aoc_cache_{}", process::id()));
+        let year = Year::new(2024).unwrap();
+        let cached = cache_dir.join("2024/01/puzzle.html");
+        fs::create_dir_all(cached.parent().unwrap()).unwrap();
+        fs::write(&cached, "<article>Part one</article>").unwrap();
+
+        let page = "<article>Part one</article><article>Part two</article>";
+        let (base_url, server) = serve_once(page);
+        let client = Client::new(&base_url, "secret", Some(cache_dir.clone()));
+        assert_eq!(client.puzzle(year, day!(1)).unwrap(), page);
+        server.join().unwrap();
+        assert_eq!(fs::read_to_string(&cached).unwrap(), page);
+
+        // the server is gone, so the cached page is used.
+        let client = Client::new(&base_url, "secret", Some(cache_dir.clone()));
+        assert_eq!(client.puzzle(year, day!(1)).unwrap(), page);
+
+        fs::remove_dir_all(cache_dir).unwrap();
+    }
+
+    #[test]
+    fn submits_answers() {
+        let (base_url, server) = serve_once(
+            "<main><article><p>That's not the right answer; your answer is too low.</p></article></main>",
+        );
+        let client = Client::new(&base_url, "secret", None);
+
+        let outcome = client
+            .submit(Year::new(2024).unwrap(), day!(1), 1, "42")
+            .unwrap();
+        assert_eq!(outcome, SubmissionOutcome::TooLow);
+        assert_eq!(
+            server.join().unwrap().trim(),
+            "POST /2024/day/1/answer HTTP/1.1"
+        );
+    }
+
+    #[test]
+    fn reports_unreachable_websites() {
+        // bind and drop a listener, so nothing listens on its port.
+        let base_url = {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            format!("http://{}", listener.local_addr().unwrap())
+        };
+        let client = Client::new(&base_url, "secret", None);
+
+        let res = client.submit(Year::new(2024).unwrap(), day!(1), 1, "42");
+        assert!(matches!(res, Err(ClientError::Connect(_))));
+    }
+
+    #[test]
+    fn reports_unexpected_responses() {
+        let (base_url, server) = serve_once("<main>Please log in.</main>");
+        let client = Client::new(&base_url, "secret", None);
+
+        let res = client.submit(Year::new(2024).unwrap(), day!(1), 1, "42");
+        assert!(matches!(res, Err(ClientError::UnexpectedResponse(_))));
+        server.join().unwrap();
+    }
+}
@@ -0,0 +1,206 @@
+/// Abstraction over the ways the template talks to the Advent of Code website.
+/// The built-in client is used by default, with aoc-cli as a fallback if it is installed.
+/// Set `AOC_BACKEND=aoc-cli` or `AOC_BACKEND=native` to always use one of them.
+use std::{env, fmt::Display, fs};
+
+use crate::template::aoc_cli::{self, AocCommandError, SubmissionOutcome};
+use crate::template::aoc_client::{Client, ClientError};
+use crate::template::markdown;
+use crate::template::{Day, Year};
+
+#[derive(Debug)]
+pub enum BackendError {
+    Cli(AocCommandError),
+    Client(ClientError),
+    UnknownBackend(String),
+}
+
+impl Display for BackendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BackendError::Cli(AocCommandError::CommandNotFound) => write!(
+                f,
+                "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
+            ),
+            BackendError::Cli(e) => write!(f, "failed to call aoc-cli: {e}"),
+            BackendError::Client(e) => write!(f, "failed to call the Advent of Code website: {e}"),
+            BackendError::UnknownBackend(name) => write!(
+                f,
+                "unknown backend \"{name}\" in AOC_BACKEND. Expected \"native\" or \"aoc-cli\"."
+            ),
+        }
+    }
+}
+
+impl From<AocCommandError> for BackendError {
+    fn from(e: AocCommandError) -> Self {
+        BackendError::Cli(e)
+    }
+}
+
+impl From<ClientError> for BackendError {
+    fn from(e: ClientError) -> Self {
+        BackendError::Client(e)
+    }
+}
+
+impl From<std::io::Error> for BackendError {
+    fn from(e: std::io::Error) -> Self {
+        BackendError::Client(ClientError::IO(e))
+    }
+}
+
+/// The description of a day as downloaded by a backend, for extracting its title and examples.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PuzzlePage {
+    /// The page of the website, downloaded by the built-in client.
+    Html(String),
+    /// The Markdown description written by aoc-cli.
+    Markdown(String),
+}
+
+impl PuzzlePage {
+    /// The title of the puzzle, e.g. "Day 1: Historian Hysteria".
+    pub fn title(&self) -> Option<String> {
+        match self {
+            PuzzlePage::Html(page) => markdown::title(page),
+            PuzzlePage::Markdown(page) => {
+                let line = page.lines().find(|line| line.contains("--- Day "))?;
+                let title = line.trim_matches(|c: char| matches!(c, '\\' | '#' | '-' | ' '));
+                (!title.is_empty()).then(|| title.to_string())
+            }
+        }
+    }
+}
+
+pub trait AocBackend {
+    /// Download the input and description of a day to the data directory. Returns the downloaded description.
+    fn download(&self, day: Day) -> Result<PuzzlePage, BackendError>;
+
+    /// Print the description of a day.
+    fn read(&self, day: Day) -> Result<(), BackendError>;
+
+    /// Submit an answer for a part and print the outcome.
+    fn submit(&self, day: Day, part: u8, answer: &str) -> Result<SubmissionOutcome, BackendError>;
+}
+
+/// Select the backend configured by the `AOC_BACKEND` environment variable.
+/// If none is configured, the built-in client is used. If aoc-cli is installed, it is used instead when no session cookie is found,
+/// and as a fallback when the built-in client fails.
+pub fn select() -> Result<Box<dyn AocBackend>, BackendError> {
+    match env::var("AOC_BACKEND").as_deref() {
+        Err(_) => {
+            let has_cli = aoc_cli::check().is_ok();
+            match Client::from_env() {
+                Ok(client) if has_cli => Ok(Box::new(FallbackBackend(NativeBackend(client)))),
+                Ok(client) => Ok(Box::new(NativeBackend(client))),
+                Err(_) if has_cli => Ok(Box::new(AocCliBackend)),
+                Err(e) => Err(e.into()),
+            }
+        }
+        Ok("native") => Ok(Box::new(NativeBackend(Client::from_env()?))),
+        Ok("aoc-cli") => {
+            aoc_cli::check()?;
+            Ok(Box::new(AocCliBackend))
+        }
+        Ok(name) => Err(BackendError::UnknownBackend(name.into())),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Talks to the website with the built-in [`Client`].
+pub struct NativeBackend(pub Client);
+
+impl AocBackend for NativeBackend {
+    fn download(&self, day: Day) -> Result<PuzzlePage, BackendError> {
+        let year = Year::current();
+        let input_path = aoc_cli::get_input_path(day);
+        let puzzle_path = aoc_cli::get_puzzle_path(day);
+
+        fs::write(&input_path, self.0.input(year, day)?)?;
+        let page = self.0.puzzle(year, day)?;
+        fs::write(&puzzle_path, markdown::render(&page))?;
+
+        println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+        println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+        Ok(PuzzlePage::Html(page))
+    }
+
+    fn read(&self, day: Day) -> Result<(), BackendError> {
+        let puzzle = self.0.puzzle(Year::current(), day)?;
+        print!("{}", markdown::render(&puzzle));
+        Ok(())
+    }
+
+    fn submit(&self, day: Day, part: u8, answer: &str) -> Result<SubmissionOutcome, BackendError> {
+        let outcome = self.0.submit(Year::current(), day, part, answer)?;
+        println!("{}", outcome.message());
+        Ok(outcome)
+    }
+}
+
+/// Shells out to the "aoc-cli" command-line.
+pub struct AocCliBackend;
+
+impl AocBackend for AocCliBackend {
+    fn download(&self, day: Day) -> Result<PuzzlePage, BackendError> {
+        aoc_cli::download(day)?;
+        let page = fs::read_to_string(aoc_cli::get_puzzle_path(day))?;
+        Ok(PuzzlePage::Markdown(page))
+    }
+
+    fn read(&self, day: Day) -> Result<(), BackendError> {
+        aoc_cli::read(day)?;
+        Ok(())
+    }
+
+    fn submit(&self, day: Day, part: u8, answer: &str) -> Result<SubmissionOutcome, BackendError> {
+        Ok(aoc_cli::submit(day, part, answer)?)
+    }
+}
+
+/// Uses the built-in client and retries with aoc-cli if it fails.
+/// Submissions are only retried if they failed before the answer was sent, so an answer is never submitted twice.
+pub struct FallbackBackend(pub NativeBackend);
+
+impl FallbackBackend {
+    /// Run `f` with the built-in client, and with aoc-cli if it fails with an error that `retry` accepts.
+    fn attempt<T>(
+        &self,
+        f: impl Fn(&dyn AocBackend) -> Result<T, BackendError>,
+        retry: impl Fn(&BackendError) -> bool,
+    ) -> Result<T, BackendError> {
+        f(&self.0).or_else(|e| {
+            if !retry(&e) {
+                return Err(e);
+            }
+
+            eprintln!("{e}");
+            eprintln!("Retrying with aoc-cli...");
+            f(&AocCliBackend)
+        })
+    }
+}
+
+impl AocBackend for FallbackBackend {
+    fn download(&self, day: Day) -> Result<PuzzlePage, BackendError> {
+        self.attempt(|backend| backend.download(day), |_| true)
+    }
+
+    fn read(&self, day: Day) -> Result<(), BackendError> {
+        self.attempt(|backend| backend.read(day), |_| true)
+    }
+
+    fn submit(&self, day: Day, part: u8, answer: &str) -> Result<SubmissionOutcome, BackendError> {
+        self.attempt(|backend| backend.submit(day, part, answer), is_unsent)
+    }
+}
+
+/// Whether a request failed before anything was sent to the website.
+fn is_unsent(e: &BackendError) -> bool {
+    matches!(
+        e,
+        BackendError::Client(ClientError::SessionNotFound | ClientError::Connect(_))
+    )
+}
@@ -0,0 +1,309 @@
+/// Statistical benchmarking engine used by the runner when `--time` is passed.
+use std::{
+    collections::HashMap,
+    env,
+    hint::black_box,
+    process,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+use tinyjson::JsonValue;
+
+/// Controls how long and how often a solution part is sampled.
+/// Every value can be overridden from the command-line, e.g. `cargo time 5 --bench-time 3000`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Number of unrecorded runs before sampling starts. (`--warmup <N>`)
+    pub warmup_rounds: u32,
+    /// Time budget for sampling, the engine stops once it is used up. (`--bench-time <MS>`)
+    pub budget: Duration,
+    /// Minimum number of samples, taken even if this exceeds the budget. (`--min-samples <N>`)
+    pub min_samples: u32,
+    /// Maximum number of samples, sampling stops once it is reached. (`--max-samples <N>`)
+    pub max_samples: u32,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup_rounds: 3,
+            budget: Duration::from_secs(1),
+            min_samples: 10,
+            max_samples: 10_000,
+        }
+    }
+}
+
+impl BenchConfig {
+    /// Reads the benchmark configuration from the command-line arguments of the current process.
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+        Self::parse(&args)
+    }
+
+    /// Reads the benchmark configuration from a list of command-line arguments.
+    pub fn parse(args: &[String]) -> Self {
+        let defaults = Self::default();
+
+        let config = Self {
+            warmup_rounds: parse_arg(args, "--warmup").unwrap_or(defaults.warmup_rounds),
+            budget: parse_arg(args, "--bench-time")
+                .map(Duration::from_millis)
+                .unwrap_or(defaults.budget),
+            min_samples: parse_arg(args, "--min-samples").unwrap_or(defaults.min_samples),
+            max_samples: parse_arg(args, "--max-samples").unwrap_or(defaults.max_samples),
+        };
+
+        if config.min_samples == 0 || config.min_samples > config.max_samples {
+            eprintln!("Expected `--min-samples` to be between 1 and `--max-samples`.");
+            process::exit(1);
+        }
+
+        config
+    }
+}
+
+fn parse_arg<T: FromStr>(args: &[String], name: &str) -> Option<T> {
+    let index = args.iter().position(|x| x == name)?;
+    match args.get(index + 1).map(|x| x.parse()) {
+        Some(Ok(value)) => Some(value),
+        _ => {
+            eprintln!("Unexpected command-line input. Format: {name} <number>");
+            process::exit(1);
+        }
+    }
+}
+
+/// Sample a function according to `config` and return the raw samples.
+pub fn sample<I: Copy, T>(func: impl Fn(I) -> T, input: I, config: &BenchConfig) -> Vec<Duration> {
+    for _ in 0..config.warmup_rounds {
+        black_box(func(black_box(input)));
+    }
+
+    let mut samples: Vec<Duration> = vec![];
+    let start = Instant::now();
+
+    while samples.len() < config.max_samples as usize
+        && (samples.len() < config.min_samples as usize || start.elapsed() < config.budget)
+    {
+        let timer = Instant::now();
+        black_box(func(black_box(input)));
+        samples.push(timer.elapsed());
+    }
+
+    samples
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Summary statistics of a set of benchmark samples. All durations are in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BenchStats {
+    pub samples: u128,
+    pub mean: f64,
+    pub min: f64,
+    pub median: f64,
+    pub p95: f64,
+    pub std_dev: f64,
+    /// Number of samples outside of the Tukey fences (1.5 × IQR below Q1 or above Q3).
+    pub outliers: u128,
+}
+
+impl BenchStats {
+    /// Compute statistics for a set of samples. Returns `None` if there are no samples.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut nanos: Vec<f64> = samples.iter().map(|x| x.as_nanos() as f64).collect();
+        nanos.sort_unstable_by(f64::total_cmp);
+
+        let len = nanos.len() as f64;
+        let mean = nanos.iter().sum::<f64>() / len;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / len;
+
+        let q1 = percentile(&nanos, 0.25);
+        let q3 = percentile(&nanos, 0.75);
+        let iqr = q3 - q1;
+        let (lower_fence, upper_fence) = (q1 - 1.5 * iqr, q3 + 1.5 * iqr);
+
+        Some(Self {
+            samples: nanos.len() as u128,
+            mean,
+            min: nanos[0],
+            median: percentile(&nanos, 0.5),
+            p95: percentile(&nanos, 0.95),
+            std_dev: variance.sqrt(),
+            outliers: nanos
+                .iter()
+                .filter(|x| **x < lower_fence || **x > upper_fence)
+                .count() as u128,
+        })
+    }
+
+    pub fn mean_duration(&self) -> Duration {
+        nanos_to_duration(self.mean)
+    }
+
+    /// Format the statistics as a single line, e.g. for printing below a part's result.
+    pub fn summary(&self) -> String {
+        format!(
+            "min {:.1?} · median {:.1?} · p95 {:.1?} · σ {:.1?} · {} outlier{}",
+            nanos_to_duration(self.min),
+            nanos_to_duration(self.median),
+            nanos_to_duration(self.p95),
+            nanos_to_duration(self.std_dev),
+            self.outliers,
+            if self.outliers == 1 { "" } else { "s" }
+        )
+    }
+}
+
+/// Linear interpolation between closest ranks of sorted values.
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = p * (sorted.len() - 1) as f64;
+    let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn nanos_to_duration(nanos: f64) -> Duration {
+    Duration::from_nanos(nanos.round() as u64)
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&BenchStats> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &BenchStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert("mean".into(), JsonValue::Number(value.mean));
+        map.insert("min".into(), JsonValue::Number(value.min));
+        map.insert("median".into(), JsonValue::Number(value.median));
+        map.insert("p95".into(), JsonValue::Number(value.p95));
+        map.insert("std_dev".into(), JsonValue::Number(value.std_dev));
+        map.insert("outliers".into(), JsonValue::Number(value.outliers as f64));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for BenchStats {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected stats.{key} to be a number."))
+        };
+
+        Ok(BenchStats {
+            samples: number("samples")? as u128,
+            mean: number("mean")?,
+            min: number("min")?,
+            median: number("median")?,
+            p95: number("p95")?,
+            std_dev: number("std_dev")?,
+            outliers: number("outliers")? as u128,
+        })
+    }
+}
+
+/// Read an optional stats object from a JSON object, treating `null` and missing keys as `None`.
+pub(crate) fn stats_from_json(
+    json: &HashMap<String, JsonValue>,
+    key: &str,
+) -> Result<Option<BenchStats>, String> {
+    match json.get(key) {
+        None => Ok(None),
+        Some(v) if v.is_null() => Ok(None),
+        Some(v) => BenchStats::try_from(v).map(Some),
+    }
+}
+
+/// Write an optional stats object as JSON, using `null` for `None`.
+pub(crate) fn stats_to_json(stats: Option<&BenchStats>) -> JsonValue {
+    match stats {
+        Some(stats) => JsonValue::from(stats),
+        None => JsonValue::Null,
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{BenchConfig, BenchStats, sample};
+
+    fn durations(nanos: &[u64]) -> Vec<Duration> {
+        nanos.iter().map(|x| Duration::from_nanos(*x)).collect()
+    }
+
+    #[test]
+    fn computes_statistics() {
+        let stats = BenchStats::from_samples(&durations(&[10, 20, 30, 40, 50])).unwrap();
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.mean, 30.0);
+        assert_eq!(stats.min, 10.0);
+        assert_eq!(stats.median, 30.0);
+        assert_eq!(stats.p95, 48.0);
+        assert!((stats.std_dev - 200_f64.sqrt()).abs() < 1e-9);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn counts_outliers() {
+        let stats =
+            BenchStats::from_samples(&durations(&[10, 11, 10, 12, 11, 10, 11, 500])).unwrap();
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.median, 11.0);
+    }
+
+    #[test]
+    fn handles_empty_samples() {
+        assert_eq!(BenchStats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn respects_sample_limits() {
+        let config = BenchConfig {
+            warmup_rounds: 0,
+            budget: Duration::from_secs(60),
+            min_samples: 1,
+            max_samples: 25,
+        };
+        assert_eq!(sample(|x: u32| x + 1, 1, &config).len(), 25);
+
+        let config = BenchConfig {
+            warmup_rounds: 0,
+            budget: Duration::ZERO,
+            min_samples: 7,
+            max_samples: 100,
+        };
+        assert_eq!(sample(|x: u32| x + 1, 1, &config).len(), 7);
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let stats = BenchStats::from_samples(&durations(&[10, 20, 30])).unwrap();
+        let json = tinyjson::JsonValue::from(&stats);
+        assert_eq!(BenchStats::try_from(&json).unwrap(), stats);
+    }
+}
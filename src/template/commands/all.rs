@@ -1,5 +1,20 @@
-use crate::template::{all_days, run_multi::run_multi};
+use crate::template::{
+    all_days,
+    limits::Limits,
+    params::ParamOverride,
+    run_multi::{RunOptions, run_multi},
+};
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+pub fn handle(is_release: bool, jobs: usize, limits: &Limits, params: &[ParamOverride]) {
+    let options = RunOptions {
+        is_release,
+        is_dhat: false,
+        is_timed: false,
+        bench_args: &[],
+        limits,
+        params,
+        shares_params: true,
+    };
+
+    run_multi(&all_days().collect(), &options, jobs);
 }
@@ -1,16 +1,23 @@
-use crate::template::{Day, aoc_cli};
 use std::process;
 
+use crate::template::backend::{self, BackendError, PuzzlePage};
+use crate::template::{Day, examples};
+
 pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
+    if let Err(e) = fetch(day) {
+        eprintln!("{e}");
         process::exit(1);
+    };
+}
+
+/// Download the input and description of a day and extract its examples into empty example files.
+/// Returns the downloaded description.
+pub fn fetch(day: Day) -> Result<PuzzlePage, BackendError> {
+    let page = backend::select()?.download(day)?;
+
+    for path in examples::write(day, &examples::extract(&page))? {
+        println!("🎄 Successfully wrote example to \"{path}\".");
     }
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
-    };
+    Ok(page)
 }
@@ -1,17 +1,20 @@
 use std::process;
 
-use crate::template::{Day, aoc_cli};
+use crate::template::{Day, Year, aoc_client, backend, markdown};
 
 pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
-        process::exit(1);
-    }
+    let result = backend::select().and_then(|backend| backend.read(day));
+
+    if let Err(e) = result {
+        // a page that was downloaded before can be rendered without network access.
+        if let Some(page) = aoc_client::cached_puzzle(Year::current(), day) {
+            eprintln!("{e}");
+            eprintln!("Showing the cached description, it might be outdated.");
+            print!("{}", markdown::render(&page));
+            return;
+        }
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+        eprintln!("{e}");
         process::exit(1);
     };
 }
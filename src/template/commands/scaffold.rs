@@ -1,13 +1,102 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
     process,
 };
 
-use crate::template::Day;
+use crate::template::backend::PuzzlePage;
+use crate::template::commands::download;
+use crate::template::examples::{self, Example};
+use crate::template::{Day, Year};
 
-const MODULE_TEMPLATE: &str =
-    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+macro_rules! builtin_template {
+    ($name:literal) => {
+        (
+            $name,
+            include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/src/templates/",
+                $name,
+                ".txt"
+            )),
+        )
+    };
+}
+
+/// Module templates that ship with the template, selectable with `--template <name>`.
+const TEMPLATES: [(&str, &str); 5] = [
+    builtin_template!("default"),
+    builtin_template!("grid"),
+    builtin_template!("graph"),
+    builtin_template!("lines"),
+    builtin_template!("parse-then-solve"),
+];
+
+/// Directory of user templates, e.g. `templates/my-template.txt`. These take precedence over built-in templates.
+const USER_TEMPLATE_DIR: &str = "templates";
+
+/// Names of all built-in and user templates.
+fn template_names() -> Vec<String> {
+    let mut names: Vec<String> = TEMPLATES.iter().map(|(name, _)| name.to_string()).collect();
+
+    if let Ok(entries) = fs::read_dir(USER_TEMPLATE_DIR) {
+        names.extend(entries.filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            name.strip_suffix(".txt").map(String::from)
+        }));
+    }
+
+    names.sort_unstable();
+    names.dedup();
+    names
+}
+
+fn load_template(name: &str) -> Result<String, String> {
+    if let Ok(template) = fs::read_to_string(format!("{USER_TEMPLATE_DIR}/{name}.txt")) {
+        return Ok(template);
+    }
+
+    TEMPLATES
+        .iter()
+        .find(|(builtin, _)| *builtin == name)
+        .map(|(_, template)| template.to_string())
+        .ok_or_else(|| {
+            format!(
+                "Unknown template \"{name}\". Available templates: {}.",
+                template_names().join(", ")
+            )
+        })
+}
+
+/// Fill in the placeholders of a module template:
+///  - `%DAY_NUMBER%` and `%YEAR%`.
+///  - `%TITLE%`: the puzzle title, e.g. "Day 1: Historian Hysteria", or "Day 1" if unknown.
+///  - `%PART_ONE_ANSWER%` and `%PART_TWO_ANSWER%`: the expected example answers, e.g. `Some(42)`, `Some("abc")` or `None`.
+///  - `%PART_TWO_EXAMPLE%`: the example of part two in [`crate::example_tests!`], `example` or `example 2`.
+fn render_template(
+    template: &str,
+    year: Year,
+    day: Day,
+    title: Option<&str>,
+    examples: &[Example; 2],
+) -> String {
+    let part_two_example = if examples::has_separate_part_two(examples) {
+        "example 2"
+    } else {
+        "example"
+    };
+
+    template
+        .replace("%DAY_NUMBER%", &day.into_inner().to_string())
+        .replace("%YEAR%", &year.to_string())
+        .replace(
+            "%TITLE%",
+            &title.map_or_else(|| format!("Day {}", day.into_inner()), String::from),
+        )
+        .replace("%PART_ONE_ANSWER%", &expected_answer(&examples[0]))
+        .replace("%PART_TWO_ANSWER%", &expected_answer(&examples[1]))
+        .replace("%PART_TWO_EXAMPLE%", part_two_example)
+}
 
 fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
     let mut file = OpenOptions::new();
@@ -19,19 +108,69 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
     file.truncate(true).write(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(path)
+/// Create an empty file. Returns `None` if the file already exists.
+fn create_file(path: &str) -> Result<Option<File>, std::io::Error> {
+    match OpenOptions::new().write(true).create_new(true).open(path) {
+        Ok(file) => Ok(Some(file)),
+        Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Format an example answer as the expected value of a generated test, e.g. `Some(42)`, or `Some("abc")` for text answers.
+fn expected_answer(example: &Example) -> String {
+    match example.answer.as_deref() {
+        Some(x) if x.parse::<i128>().is_ok() => format!("Some({x})"),
+        Some(x) if !x.is_empty() => format!("Some({x:?})"),
+        _ => "None".into(),
+    }
+}
+
+/// Binaries of events with their own directory are not discovered by cargo and need a manifest entry.
+fn register_bin(year: Year, day: Day, module_path: &str) -> Result<bool, std::io::Error> {
+    let manifest_path = "Cargo.toml";
+    let path = module_path.trim_start_matches("./");
+    let manifest = fs::read_to_string(manifest_path)?;
+
+    if manifest.contains(&format!("path = \"{path}\"")) {
+        return Ok(false);
+    }
+
+    let mut file = OpenOptions::new().append(true).open(manifest_path)?;
+    write!(
+        file,
+        "\n[[bin]]\nname = \"{}\"\npath = \"{path}\"\n",
+        year.bin_name(day)
+    )?;
+    Ok(true)
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+/// Create the solution module and data files of a day from the template called `template_name`.
+/// With `download`, the input and description are downloaded before the module is written, so the title, examples and their answers can be filled in.
+/// If the download fails, the module is still written without them, but the command exits with a non-zero status.
+pub fn handle(day: Day, overwrite: bool, download: bool, template_name: &str) {
+    let year = Year::current();
 
+    let template = load_template(template_name).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        process::exit(1);
+    });
+
+    let data_dir = year.data_dir();
+    let input_path = format!("{data_dir}/inputs/{day}.txt");
+    let example_path = format!("{data_dir}/examples/{day}.txt");
+    let module_path = year.bin_path(day);
+
+    if year.has_own_directory() {
+        for folder in ["inputs", "examples", "puzzles", "answers"] {
+            if let Err(e) = fs::create_dir_all(format!("{data_dir}/{folder}")) {
+                eprintln!("Failed to create data directory: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    // create the module first, so nothing is downloaded if it already exists.
     let mut file = match safe_create_file(&module_path, overwrite) {
         Ok(file) => file,
         Err(e) => {
@@ -40,11 +179,20 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
+    let page = if download {
+        download::fetch(day)
+            .inspect_err(|e| eprintln!("Failed to download: {e}"))
+            .ok()
+    } else {
+        None
+    };
+
+    let title = page.as_ref().and_then(PuzzlePage::title);
+    let examples = page.as_ref().map(examples::extract).unwrap_or_default();
+
+    match file
+        .write_all(render_template(&template, year, day, title.as_deref(), &examples).as_bytes())
+    {
         Ok(()) => {
             println!("Created module file \"{}\"", &module_path);
         }
@@ -54,10 +202,27 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     }
 
+    if year.has_own_directory() {
+        match register_bin(year, day, &module_path) {
+            Ok(true) => {
+                println!(
+                    "Registered binary \"{}\" in \"Cargo.toml\"",
+                    year.bin_name(day)
+                );
+            }
+            Ok(false) => {}
+            Err(e) => {
+                eprintln!("Failed to register binary: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
     match create_file(&input_path) {
-        Ok(_) => {
+        Ok(Some(_)) => {
             println!("Created empty input file \"{}\"", &input_path);
         }
+        Ok(None) => {}
         Err(e) => {
             eprintln!("Failed to create input file: {e}");
             process::exit(1);
@@ -65,9 +230,10 @@ pub fn handle(day: Day, overwrite: bool) {
     }
 
     match create_file(&example_path) {
-        Ok(_) => {
+        Ok(Some(_)) => {
             println!("Created empty example file \"{}\"", &example_path);
         }
+        Ok(None) => {}
         Err(e) => {
             eprintln!("Failed to create example file: {e}");
             process::exit(1);
@@ -76,4 +242,67 @@ pub fn handle(day: Day, overwrite: bool) {
 
     println!("---");
     println!("🎄 Type `cargo solve {day}` to run your solution.");
+
+    if download && page.is_none() {
+        process::exit(1);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{TEMPLATES, load_template, render_template};
+    use crate::{
+        day,
+        template::{Year, examples::Example},
+    };
+
+    #[test]
+    fn renders_placeholders() {
+        let examples = [
+            Example {
+                input: Some("1\n".into()),
+                answer: Some("42".into()),
+            },
+            Example {
+                input: Some("2\n".into()),
+                answer: Some("abc".into()),
+            },
+        ];
+
+        let rendered = render_template(
+            "%TITLE% %YEAR% %DAY_NUMBER% %PART_ONE_ANSWER% %PART_TWO_ANSWER% %PART_TWO_EXAMPLE%",
+            Year::new(2024).unwrap(),
+            day!(5),
+            Some("Day 5: Print Queue"),
+            &examples,
+        );
+
+        assert_eq!(
+            rendered,
+            "Day 5: Print Queue 2024 5 Some(42) Some(\"abc\") example 2"
+        );
+    }
+
+    #[test]
+    fn renders_builtin_templates() {
+        for (name, template) in TEMPLATES {
+            let rendered = render_template(
+                template,
+                Year::new(2024).unwrap(),
+                day!(1),
+                None,
+                &Default::default(),
+            );
+            assert!(!rendered.contains('%'), "{name} has unknown placeholders");
+            assert!(rendered.starts_with("//! Day 1 (Advent of Code 2024)"));
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_templates() {
+        let error = load_template("does-not-exist").unwrap_err();
+        assert!(error.contains("grid"));
+    }
 }
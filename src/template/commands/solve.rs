@@ -1,9 +1,82 @@
-use std::process::{Command, Stdio};
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+use std::process::{self, Command, Stdio};
+use std::thread;
+use std::time::{Duration, SystemTime};
 
-use crate::template::Day;
+use crate::template::input::InputSource;
+use crate::template::limits::Limits;
+use crate::template::params::{self, ParamOverride};
+use crate::template::report::{PARSE_PART, PartReport};
+use crate::template::run_multi::{RunOptions, child_commands, run_multi};
+use crate::template::table::{self, Cell};
+use crate::template::{ANSI_BOLD, ANSI_CLEAR, ANSI_DIM, ANSI_RESET, Day, Year, team};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+/// How often watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    day: Day,
+    release: bool,
+    dhat: bool,
+    submit_part: Option<u8>,
+    format: Option<String>,
+    input: &InputSource,
+    params: &[ParamOverride],
+    watch: bool,
+    test: bool,
+) {
+    let options = RunOptions {
+        is_release: release,
+        is_dhat: dhat,
+        is_timed: false,
+        bench_args: &[],
+        limits: &Limits::default(),
+        params,
+        shares_params: false,
+    };
+
+    if test && !watch {
+        eprintln!("`--test` can only be used together with `--watch`.");
+        process::exit(1);
+    }
+
+    if watch {
+        if submit_part.is_some() || format.is_some() || *input == InputSource::Stdin {
+            eprintln!("`--watch` can not be combined with `--submit`, `--format` or `--stdin`.");
+            process::exit(1);
+        }
+
+        if !test && input.is_puzzle() && !team::members(day).is_empty() {
+            eprintln!(
+                "The inputs of day {day} belong to team members, pass `--member <NAME>` to watch."
+            );
+            process::exit(1);
+        }
+
+        watch_day(day, &options, input, test);
+    }
+
+    // NOTE: team mode runs every member's input and prints a matrix of their answers.
+    if input.is_puzzle() && format.is_none() && !team::members(day).is_empty() {
+        if submit_part.is_some() {
+            eprintln!(
+                "The inputs of day {day} belong to team members, pass `--member <NAME>` to submit."
+            );
+            process::exit(1);
+        }
+
+        run_multi(&HashSet::from([day]), &options, 1);
+        return;
+    }
+
+    let mut cmd_args = vec![
+        "run".to_string(),
+        "--bin".to_string(),
+        Year::current().bin_name(day),
+    ];
 
     if dhat {
         cmd_args.extend([
@@ -23,6 +96,14 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         cmd_args.push(submit_part.to_string());
     }
 
+    if let Some(format) = format {
+        cmd_args.push("--format".to_string());
+        cmd_args.push(format);
+    }
+
+    cmd_args.extend(input.to_args());
+    cmd_args.extend(params::to_args(params, day, false));
+
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
@@ -32,3 +113,199 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
 
     cmd.wait().unwrap();
 }
+
+/// Re-run a day whenever its source, the library, its input or one of its examples changes.
+/// The results of every run are shown next to the results of the previous run.
+/// With `test`, the tests of the day are re-run instead, e.g. the example tests of [`crate::example_tests!`].
+fn watch_day(day: Day, options: &RunOptions, input: &InputSource, test: bool) -> ! {
+    let paths = watched_paths(day, input, test);
+    let mut previous: Vec<PartReport> = vec![];
+    let mut run = 0;
+
+    loop {
+        run += 1;
+        let modified = modified_times(&paths);
+
+        print!("{ANSI_CLEAR}");
+        println!(
+            "{ANSI_BOLD}Day {day}{ANSI_RESET} {ANSI_DIM}· {} · run {run}{ANSI_RESET}\n",
+            if test {
+                "tests".into()
+            } else {
+                input.path(day)
+            }
+        );
+
+        if test {
+            run_tests(day, options);
+        } else {
+            // NOTE: build errors and output of the solution are forwarded while it runs.
+            let output = child_commands::run_solution(day, options, input, true).unwrap();
+
+            if output.reports.is_empty() {
+                println!("Not solved.");
+            } else {
+                comparison(&output.reports, &previous, input)
+                    .iter()
+                    .for_each(|line| println!("{line}"));
+                previous = output.reports;
+            }
+        }
+
+        println!("\n{ANSI_DIM}Watching for changes, press Ctrl+C to stop.{ANSI_RESET}");
+
+        // NOTE: changes made while the day ran trigger the next run right away.
+        while modified_times(&paths) == modified {
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+}
+
+/// Run the tests of a day's solution, forwarding their output.
+fn run_tests(day: Day, options: &RunOptions) {
+    let bin_name = Year::current().bin_name(day);
+    let mut args = vec!["test", "--bin", &bin_name];
+    if options.is_release {
+        args.push("--release");
+    }
+
+    if let Err(e) = Command::new("cargo").args(&args).status() {
+        eprintln!("Failed to run tests: {e}");
+    }
+}
+
+/// The solution of a day, the library, the selected input and all examples of the day.
+/// Tests only run against examples, so the input is not watched with `test`.
+fn watched_paths(day: Day, input: &InputSource, test: bool) -> Vec<String> {
+    let mut paths = vec![Year::current().bin_path(day), "src/lib.rs".into()];
+
+    if !test && *input != InputSource::Stdin {
+        paths.push(input.path(day));
+    }
+
+    let examples = format!("{}/examples", Year::current().data_dir());
+    if let Ok(entries) = fs::read_dir(&examples) {
+        paths.extend(
+            entries
+                .filter_map(Result::ok)
+                .filter_map(|entry| entry.file_name().into_string().ok())
+                .filter(|name| name.starts_with(&day.to_string()) && name.ends_with(".txt"))
+                .map(|name| format!("{examples}/{name}")),
+        );
+    }
+
+    paths.sort_unstable();
+    paths.dedup();
+    paths
+}
+
+/// Modification times of all watched paths. Missing files have no modification time.
+fn modified_times(paths: &[String]) -> Vec<Option<SystemTime>> {
+    paths
+        .iter()
+        .map(|path| {
+            fs::metadata(Path::new(path))
+                .and_then(|x| x.modified())
+                .ok()
+        })
+        .collect()
+}
+
+/// Format the results of the current run next to the results of the previous run.
+fn comparison(current: &[PartReport], previous: &[PartReport], input: &InputSource) -> Vec<String> {
+    let mut parts: Vec<u8> = current.iter().map(|report| report.part).collect();
+    parts.sort_unstable();
+    parts.dedup();
+
+    let mut rows = vec![vec![
+        Cell::plain(""),
+        Cell::colored("Current", ANSI_DIM),
+        Cell::colored("Previous", ANSI_DIM),
+    ]];
+
+    for part in parts {
+        let label = if part == PARSE_PART {
+            "Parse".to_string()
+        } else {
+            format!("Part {part}")
+        };
+
+        rows.push(vec![
+            Cell::plain(label),
+            table::part_cell(current, part, input),
+            if previous.is_empty() {
+                Cell::plain("-")
+            } else {
+                table::part_cell(previous, part, input)
+            },
+        ]);
+    }
+
+    table::render(&rows)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{comparison, watched_paths};
+    use crate::{
+        day,
+        template::{
+            Year,
+            input::InputSource,
+            report::{PARSE_PART, PartReport, PartStatus},
+            test_support::{report, strip_colors},
+        },
+    };
+
+    fn lines(current: &[PartReport], previous: &[PartReport]) -> Vec<String> {
+        comparison(current, previous, &InputSource::Example(Some(9)))
+            .iter()
+            .map(|line| strip_colors(line))
+            .collect()
+    }
+
+    #[test]
+    fn compares_runs() {
+        let previous = [
+            report(1, Some("41"), PartStatus::Solved, 2_000),
+            report(2, None, PartStatus::Panicked, 0),
+        ];
+        let current = [
+            report(PARSE_PART, None, PartStatus::Solved, 500),
+            report(1, Some("42"), PartStatus::Solved, 1_000),
+            report(2, Some("7"), PartStatus::Solved, 3_000),
+        ];
+
+        assert_eq!(
+            lines(&current, &previous),
+            [
+                "         Current      Previous",
+                "Parse    (500.0ns)    -",
+                "Part 1   42 (1.0µs)   41 (2.0µs)",
+                "Part 2   7 (3.0µs)    ✖ panicked",
+            ]
+        );
+    }
+
+    #[test]
+    fn shows_first_run() {
+        let current = [report(1, Some("42"), PartStatus::Solved, 1_000)];
+        assert_eq!(
+            lines(&current, &[]),
+            ["         Current      Previous", "Part 1   42 (1.0µs)   -"]
+        );
+    }
+
+    #[test]
+    fn watches_inputs_of_members() {
+        let data_dir = Year::current().data_dir();
+        let paths = watched_paths(day!(1), &InputSource::Member("alice".into()), false);
+        assert!(paths.contains(&format!("{data_dir}/inputs/01/alice.txt")));
+        assert!(!paths.contains(&format!("{data_dir}/inputs/01.txt")));
+
+        let paths = watched_paths(day!(1), &InputSource::Puzzle, true);
+        assert!(!paths.iter().any(|path| path.contains("/inputs/")));
+    }
+}
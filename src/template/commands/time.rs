@@ -1,29 +1,93 @@
 use std::collections::HashSet;
+use std::process;
 
-use crate::template::run_multi::run_multi;
+use crate::template::limits::Limits;
+use crate::template::params::ParamOverride;
+use crate::template::run_multi::{RunOptions, run_multi};
 use crate::template::timings::Timings;
-use crate::template::{Day, all_days, readme_benchmarks};
+use crate::template::{Day, all_days, compare, history, readme_benchmarks};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
+/// Options for comparing fresh timings against stored ones (`--compare`).
+pub struct CompareOptions {
+    /// Regressions beyond this percentage make the command exit with a non-zero status.
+    pub threshold: f64,
+    /// Changes within this percentage are reported as unchanged.
+    pub noise: f64,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    dhat: bool,
+    bench_args: &[String],
+    compare: Option<CompareOptions>,
+    limits: &Limits,
+    params: &[ParamOverride],
+) {
     let stored_timings = Timings::read_from_file();
 
     let days_to_run = day.map_or_else(
         || {
             if run_all {
                 all_days().collect()
+            } else if compare.is_some() {
+                // when comparing, only days with stored timings are of interest.
+                stored_timings.data.iter().map(|t| t.day).collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
                 all_days()
-                    .filter(|day| !stored_timings.is_day_complete(*day))
+                    .filter(|day| !stored_timings.is_day_complete(day))
                     .collect()
             }
         },
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let options = RunOptions {
+        is_release: true,
+        is_dhat: dhat,
+        is_timed: true,
+        bench_args,
+        limits,
+        params,
+        shares_params: day.is_none(),
+    };
+
+    let timings = run_multi(&days_to_run, &options, 1).unwrap();
+
+    let has_regressions = compare.is_some_and(|options| {
+        let deltas = compare::compare(&stored_timings, &timings, options.noise);
+        println!();
+
+        if deltas.is_empty() {
+            println!("No stored timings to compare against.");
+            return false;
+        }
+
+        compare::print_table(&deltas, options.threshold);
+
+        let regressions = deltas
+            .iter()
+            .filter(|x| x.is_regression(options.threshold))
+            .count();
+
+        if regressions > 0 {
+            eprintln!(
+                "\n{regressions} part(s) failed or regressed by more than {:.1}%.",
+                options.threshold
+            );
+        }
+
+        regressions > 0
+    });
 
     if store {
+        if let Err(e) = history::append(&history::HistoryEntry::capture(timings.clone())) {
+            eprintln!("Failed to append benchmarks to history: {e}");
+        }
+
         let merged_timings = stored_timings.merge(&timings);
         merged_timings.store_file().unwrap();
 
@@ -37,4 +101,19 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
             }
         }
     }
+
+    if has_regressions {
+        process::exit(1);
+    }
+}
+
+/// Print how the runtime of a day evolved over all stored benchmark runs (`--history`).
+pub fn handle_history(day: Day) {
+    match history::read_from_file() {
+        Ok(entries) => history::print_day(&entries, day),
+        Err(e) => {
+            eprintln!("Failed to read benchmark history: {e}");
+            process::exit(1);
+        }
+    }
 }
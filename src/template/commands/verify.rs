@@ -0,0 +1,110 @@
+use std::process;
+
+use crate::template::answers::{self, Verdict};
+use crate::template::limits::Limits;
+use crate::template::params::ParamOverride;
+use crate::template::run_multi::{RunOptions, run_day};
+use crate::template::team::{self, MemberReports};
+use crate::template::{ANSI_BOLD, ANSI_DIM, ANSI_GREEN, ANSI_RED, ANSI_RESET, Day, all_days};
+
+/// Run solutions and compare their answers against the recorded answers.
+/// Without a day, every day with a recorded answer is verified.
+/// Days whose inputs belong to team members are verified against the answers of every member.
+pub fn handle(day: Option<Day>, is_release: bool, params: &[ParamOverride]) {
+    let days: Vec<Day> = day.map_or_else(
+        || {
+            all_days()
+                .filter(|day| answers::has_answers(*day) || has_member_answers(*day))
+                .collect()
+        },
+        |day| vec![day],
+    );
+
+    let options = RunOptions {
+        is_release,
+        is_dhat: false,
+        is_timed: false,
+        bench_args: &[],
+        limits: &Limits::default(),
+        params,
+        shares_params: day.is_none(),
+    };
+
+    if days.is_empty() {
+        println!("No recorded answers to verify against.");
+        return;
+    }
+
+    let mut failures = 0;
+
+    for day in days {
+        let output = run_day(day, &options, false);
+
+        if output.reports.is_empty() && output.members.is_empty() {
+            output.stderr.iter().for_each(|line| eprintln!("{line}"));
+        }
+
+        // NOTE: without team members, the day's only input is the puzzle input.
+        let runs = if output.members.is_empty() {
+            vec![(None, output.reports)]
+        } else {
+            output
+                .members
+                .into_iter()
+                .map(|MemberReports { member, reports }| (Some(member), reports))
+                .collect()
+        };
+
+        for (member, reports) in runs {
+            for part in [1, 2] {
+                let answer = reports
+                    .iter()
+                    .find(|report| report.part == part)
+                    .and_then(|report| report.answer.as_deref());
+
+                let (label, expected) = match &member {
+                    Some(member) => (
+                        format!("{ANSI_BOLD}Day {day}{ANSI_RESET} Part {part} ({member}):"),
+                        answers::read_member(day, member, part),
+                    ),
+                    None => (
+                        format!("{ANSI_BOLD}Day {day}{ANSI_RESET} Part {part}:"),
+                        answers::read(day, part),
+                    ),
+                };
+
+                match answers::verdict(expected.as_deref(), answer) {
+                    Verdict::Correct => {
+                        println!("{label} {ANSI_GREEN}✔{ANSI_RESET} {}", answer.unwrap())
+                    }
+                    Verdict::Incorrect { expected } => {
+                        failures += 1;
+                        println!(
+                            "{label} {ANSI_RED}✖ got {}, expected {expected}{ANSI_RESET}",
+                            answer.unwrap_or("nothing")
+                        );
+                    }
+                    Verdict::Unknown => {
+                        if answer.is_some() {
+                            println!("{label} {ANSI_DIM}no recorded answer{ANSI_RESET}");
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    if failures > 0 {
+        eprintln!("\n{failures} part(s) differ from their recorded answers.");
+        process::exit(1);
+    }
+}
+
+/// Whether an answer has been recorded for any team member of `day`.
+fn has_member_answers(day: Day) -> bool {
+    team::members(day).iter().any(|member| {
+        [1, 2]
+            .iter()
+            .any(|part| answers::read_member(day, member, *part).is_some())
+    })
+}
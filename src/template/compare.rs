@@ -0,0 +1,305 @@
+/// Module that compares fresh benchmark results against stored timings.
+use std::time::Duration;
+
+use crate::template::report::PartStatus;
+use crate::template::timings::{Timing, Timings};
+use crate::template::{ANSI_BOLD, ANSI_DIM, ANSI_GREEN, ANSI_RED, ANSI_RESET, Day};
+
+/// Classification of the runtime change of a part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    Faster,
+    Slower,
+    /// The change is within the noise threshold.
+    Unchanged,
+    /// The part has no timing anymore, because it failed, was killed or is unsolved.
+    Failed(PartStatus),
+}
+
+/// Runtime change of a single part compared to its stored timing.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartDelta {
+    pub day: Day,
+    pub part: u8,
+    pub stored_nanos: f64,
+    pub current_nanos: f64,
+    /// Relative change in percent, positive values mean the part got slower.
+    pub percent: f64,
+    pub change: Change,
+}
+
+impl PartDelta {
+    /// Whether the part got slower by more than `threshold` percent, or failed.
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        match self.change {
+            Change::Slower => self.percent > threshold,
+            Change::Failed(_) => true,
+            Change::Faster | Change::Unchanged => false,
+        }
+    }
+}
+
+/// Compare all parts present in both `stored` and `current`.
+/// Changes of at most `noise` percent are classified as [`Change::Unchanged`].
+/// Parts that failed or are unsolved in `current` are classified as [`Change::Failed`], with an infinite runtime.
+pub fn compare(stored: &Timings, current: &Timings, noise: f64) -> Vec<PartDelta> {
+    let mut deltas = vec![];
+
+    for timing in &current.data {
+        let Some(stored_timing) = stored.data.iter().find(|t| t.day == timing.day) else {
+            continue;
+        };
+
+        for part in [1, 2] {
+            let Some(stored_nanos) = part_nanos(stored_timing, part) else {
+                continue;
+            };
+
+            // parts that are neither timed nor failed returned no answer.
+            let current_nanos = match timing.failure(part) {
+                None => part_nanos(timing, part).ok_or(PartStatus::Unsolved),
+                Some(status) => Err(status),
+            };
+
+            let current_nanos = match current_nanos {
+                Ok(current_nanos) => current_nanos,
+                Err(status) => {
+                    deltas.push(PartDelta {
+                        day: timing.day,
+                        part,
+                        stored_nanos,
+                        current_nanos: f64::INFINITY,
+                        percent: f64::INFINITY,
+                        change: Change::Failed(status),
+                    });
+                    continue;
+                }
+            };
+
+            let percent = (current_nanos - stored_nanos) / stored_nanos * 100.0;
+
+            let change = if percent.abs() <= noise {
+                Change::Unchanged
+            } else if percent < 0.0 {
+                Change::Faster
+            } else {
+                Change::Slower
+            };
+
+            deltas.push(PartDelta {
+                day: timing.day,
+                part,
+                stored_nanos,
+                current_nanos,
+                percent,
+                change,
+            });
+        }
+    }
+
+    deltas
+}
+
+/// Mean execution time of a part in nanoseconds.
+/// Falls back to the formatted timing for timings that were stored without statistics.
+pub(crate) fn part_nanos(timing: &Timing, part: u8) -> Option<f64> {
+    let (formatted, stats) = match part {
+        1 => (&timing.part_1, &timing.part_1_stats),
+        2 => (&timing.part_2, &timing.part_2_stats),
+        _ => return None,
+    };
+
+    stats
+        .map(|x| x.mean)
+        .or_else(|| formatted.as_deref().and_then(parse_duration))
+        .filter(|x| *x > 0.0)
+}
+
+fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
+    s.split(postfix).next()?.parse().ok()
+}
+
+/// Parse a duration formatted with `{:?}` to nanoseconds.
+fn parse_duration(s: &str) -> Option<f64> {
+    // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
+    match s.trim() {
+        s if s.ends_with("ns") => parse_to_float(s, "ns"),
+        s if s.ends_with("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
+        s if s.ends_with("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
+        s if s.ends_with('s') => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
+        _ => None,
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn format_nanos(nanos: f64) -> String {
+    format!("{:.1?}", Duration::from_nanos(nanos.round() as u64))
+}
+
+/// Print a table of all deltas, highlighting regressions beyond `threshold` percent.
+pub fn print_table(deltas: &[PartDelta], threshold: f64) {
+    println!(
+        "{ANSI_BOLD}{:<5} {:<5} {:>12} {:>12} {:>10}{ANSI_RESET}",
+        "Day", "Part", "Stored", "Current", "Delta"
+    );
+
+    for delta in deltas {
+        let color = match delta.change {
+            Change::Faster => ANSI_GREEN,
+            Change::Slower | Change::Failed(_) => ANSI_RED,
+            Change::Unchanged => ANSI_DIM,
+        };
+
+        let label = match delta.change {
+            Change::Faster => "faster",
+            Change::Slower | Change::Failed(_) if delta.is_regression(threshold) => "regression",
+            Change::Slower | Change::Failed(_) => "slower",
+            Change::Unchanged => "unchanged",
+        };
+
+        // failed parts have no runtime, show their status instead.
+        let (current, percent) = match delta.change {
+            Change::Failed(status) => (status.as_str().to_string(), "-".to_string()),
+            _ => (
+                format_nanos(delta.current_nanos),
+                format!("{:+.1}%", delta.percent),
+            ),
+        };
+
+        println!(
+            "{:<5} {:<5} {:>12} {:>12} {color}{percent:>10} {label}{ANSI_RESET}",
+            delta.day.to_string(),
+            delta.part,
+            format_nanos(delta.stored_nanos),
+            current,
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Change, compare, parse_duration};
+    use crate::{
+        day,
+        template::{bench::BenchStats, report::PartStatus, test_support::timing, timings::Timings},
+    };
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_duration("74.0ns"), Some(74.0));
+        assert_eq!(parse_duration("1.5µs"), Some(1500.0));
+        assert_eq!(parse_duration("2.0ms"), Some(2_000_000.0));
+        assert_eq!(parse_duration("2.7s"), Some(2_700_000_000.0));
+        assert_eq!(parse_duration("-"), None);
+    }
+
+    #[test]
+    fn classifies_changes() {
+        let stored = Timings {
+            data: vec![timing(day!(1), Some("100.0µs"), Some("100.0µs"), 0_f64)],
+        };
+        let current = Timings {
+            data: vec![timing(day!(1), Some("50.0µs"), Some("102.0µs"), 0_f64)],
+        };
+
+        let deltas = compare(&stored, &current, 5.0);
+        assert_eq!(deltas.len(), 2);
+        assert_eq!(deltas[0].change, Change::Faster);
+        assert_eq!(deltas[0].percent, -50.0);
+        assert_eq!(deltas[1].change, Change::Unchanged);
+    }
+
+    #[test]
+    fn detects_regressions() {
+        let stored = Timings {
+            data: vec![timing(day!(1), Some("100.0µs"), Some("100.0µs"), 0_f64)],
+        };
+        let current = Timings {
+            data: vec![timing(day!(1), Some("115.0µs"), Some("130.0µs"), 0_f64)],
+        };
+
+        let deltas = compare(&stored, &current, 5.0);
+        assert_eq!(deltas[0].change, Change::Slower);
+        assert_eq!(deltas[0].is_regression(20.0), false);
+        assert_eq!(deltas[1].is_regression(20.0), true);
+    }
+
+    #[test]
+    fn reports_killed_parts_as_regressions() {
+        let stored = Timings {
+            data: vec![timing(day!(1), Some("100.0µs"), Some("100.0µs"), 0_f64)],
+        };
+        let current = Timings {
+            data: vec![timing(day!(1), Some("timeout"), Some("oom"), 0_f64)],
+        };
+
+        let deltas = compare(&stored, &current, 5.0);
+        assert_eq!(deltas.len(), 2);
+        assert_eq!(deltas[0].change, Change::Failed(PartStatus::Timeout));
+        assert_eq!(deltas[1].change, Change::Failed(PartStatus::Oom));
+        assert!(deltas.iter().all(|x| x.is_regression(20.0)));
+    }
+
+    #[test]
+    fn reports_failed_parts_as_regressions() {
+        let stored = Timings {
+            data: vec![
+                timing(day!(1), Some("100.0µs"), Some("100.0µs"), 0_f64),
+                timing(day!(2), Some("100.0µs"), None, 0_f64),
+            ],
+        };
+        let current = Timings {
+            data: vec![
+                timing(day!(1), Some("failed"), Some("panicked"), 0_f64),
+                timing(day!(2), None, None, 0_f64),
+            ],
+        };
+
+        let deltas = compare(&stored, &current, 5.0);
+        assert_eq!(deltas.len(), 3);
+        assert_eq!(deltas[0].change, Change::Failed(PartStatus::Failed));
+        assert_eq!(deltas[1].change, Change::Failed(PartStatus::Panicked));
+        assert_eq!(deltas[2].change, Change::Failed(PartStatus::Unsolved));
+        assert!(deltas.iter().all(|x| x.is_regression(20.0)));
+    }
+
+    #[test]
+    fn prefers_stats_over_formatted_timings() {
+        let stored = Timings {
+            data: vec![timing(day!(1), Some("100.0µs"), None, 0_f64)],
+        };
+        let mut current_timing = timing(day!(1), Some("1.0ms"), None, 0_f64);
+        current_timing.part_1_stats = Some(BenchStats {
+            samples: 10,
+            mean: 100_000.0,
+            min: 0.0,
+            median: 0.0,
+            p95: 0.0,
+            std_dev: 0.0,
+            outliers: 0,
+        });
+        let current = Timings {
+            data: vec![current_timing],
+        };
+
+        let deltas = compare(&stored, &current, 5.0);
+        assert_eq!(deltas.len(), 1);
+        assert_eq!(deltas[0].change, Change::Unchanged);
+    }
+
+    #[test]
+    fn skips_parts_without_stored_timings() {
+        let stored = Timings {
+            data: vec![timing(day!(1), None, Some("1ms"), 0_f64)],
+        };
+        let current = Timings {
+            data: vec![timing(day!(1), Some("1ms"), Some("1ms"), 0_f64)],
+        };
+
+        let deltas = compare(&stored, &current, 5.0);
+        assert_eq!(deltas.len(), 1);
+        assert_eq!(deltas[0].part, 2);
+    }
+}
@@ -2,13 +2,16 @@ use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
 
+use crate::template::Year;
+
 #[cfg(feature = "today")]
 use chrono::{Datelike, FixedOffset, Utc};
 
 #[cfg(feature = "today")]
 const SERVER_UTC_OFFSET: i32 = -5;
 
-/// A valid day number of advent (i.e. an integer in range 1 to 25).
+/// A valid day number of advent (i.e. an integer in range 1 to the number of puzzles of the
+/// current [`Year`]).
 ///
 /// # Display
 /// This value displays as a two digit number.
@@ -22,10 +25,16 @@ const SERVER_UTC_OFFSET: i32 = -5;
 pub struct Day(u8);
 
 impl Day {
-    /// Creates a [`Day`] from the provided value if it's in the valid range,
-    /// returns [`None`] otherwise.
+    /// Creates a [`Day`] from the provided value if it's in the valid range of the event configured
+    /// via `AOC_YEAR`, returns [`None`] otherwise.
     pub const fn new(day: u8) -> Option<Self> {
-        if day == 0 || day > 25 {
+        Self::new_in(Year::configured(), day)
+    }
+
+    /// Creates a [`Day`] from the provided value if it's in the valid range of the given event,
+    /// returns [`None`] otherwise.
+    pub const fn new_in(year: Year, day: u8) -> Option<Self> {
+        if day == 0 || day > year.day_count() {
             return None;
         }
         Some(Self(day))
@@ -39,12 +48,13 @@ impl Day {
 
 #[cfg(feature = "today")]
 impl Day {
-    /// Returns the current day if it's between the 1st and the 25th of december, `None` otherwise.
+    /// Returns the current day if an event of the current [`Year`] is running, `None` otherwise.
     pub fn today() -> Option<Self> {
         let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
         let today = Utc::now().with_timezone(&offset);
-        if today.month() == 12 && today.day() <= 25 {
-            Self::new(u8::try_from(today.day()).ok()?)
+        let year = Year::current();
+        if i32::from(year.into_inner()) == today.year() && today.month() == 12 {
+            Self::new_in(year, u8::try_from(today.day()).ok()?)
         } else {
             None
         }
@@ -76,7 +86,7 @@ impl FromStr for Day {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let day = s.parse().map_err(|_| DayFromStrError)?;
-        Self::new(day).ok_or(DayFromStrError)
+        Self::new_in(Year::current(), day).ok_or(DayFromStrError)
     }
 }
 
@@ -88,26 +98,38 @@ impl Error for DayFromStrError {}
 
 impl Display for DayFromStrError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str("expecting a day number between 1 and 25")
+        write!(
+            f,
+            "expecting a day number between 1 and {}",
+            Year::current().day_count()
+        )
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of the current event.
 pub fn all_days() -> AllDays {
     AllDays::new()
 }
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of an event, from the 1st to its last puzzle.
 pub struct AllDays {
     current: u8,
+    last: u8,
 }
 
 impl AllDays {
     #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
-        Self { current: 1 }
+        Self::for_year(Year::current())
+    }
+
+    pub fn for_year(year: Year) -> Self {
+        Self {
+            current: 1,
+            last: year.day_count(),
+        }
     }
 }
 
@@ -115,10 +137,10 @@ impl Iterator for AllDays {
     type Item = Day;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > 25 {
+        if self.current > self.last {
             return None;
         }
-        // NOTE: the iterator starts at 1 and we have verified that the value is not above 25.
+        // NOTE: the iterator starts at 1 and we have verified that the value is not above the event's last day.
         let day = Day(self.current);
         self.current += 1;
 
@@ -129,12 +151,16 @@ impl Iterator for AllDays {
 /* -------------------------------------------------------------------------- */
 
 /// Creates a [`Day`] value in a const context.
+/// The day is checked against the event of the binary it is used in, see [`Year::of_bin`].
 #[macro_export]
 macro_rules! day {
     ($day:expr) => {
         const {
-            $crate::template::Day::new($day)
-                .expect("invalid day number, expecting a value between 1 and 25")
+            $crate::template::Day::new_in(
+                $crate::template::Year::of_bin(option_env!("CARGO_BIN_NAME")),
+                $day,
+            )
+            .expect("invalid day number, expecting a value between 1 and the event's last day")
         }
     };
 }
@@ -143,11 +169,12 @@ macro_rules! day {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{Day, all_days};
+    use super::{AllDays, Day};
+    use crate::template::Year;
 
     #[test]
     fn all_days_iterator() {
-        let mut iter = all_days();
+        let mut iter = AllDays::for_year(Year::new(2024).unwrap());
 
         assert_eq!(iter.next(), Some(Day(1)));
         assert_eq!(iter.next(), Some(Day(2)));
@@ -176,6 +203,22 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn all_days_iterator_for_short_events() {
+        let days: Vec<Day> = AllDays::for_year(Year::new(2025).unwrap()).collect();
+        assert_eq!(days.len(), 12);
+        assert_eq!(days.last(), Some(&Day(12)));
+    }
+
+    #[test]
+    fn validates_day_against_event() {
+        let year = Year::new(2025).unwrap();
+        assert_eq!(Day::new_in(year, 12), Some(Day(12)));
+        assert_eq!(Day::new_in(year, 13), None);
+        assert_eq!(Day::new_in(year, 0), None);
+        assert_eq!(Day::new_in(Year::new(2024).unwrap(), 25), Some(Day(25)));
+    }
 }
 
 /* -------------------------------------------------------------------------- */
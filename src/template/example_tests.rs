@@ -0,0 +1,86 @@
+/// Helpers of the tests generated by [`crate::example_tests!`].
+use std::fmt::Debug;
+
+use crate::template::{Day, input::InputSource};
+
+/// Read the example of a day, or one of its numbered examples. Returns the example's path and contents.
+/// # Panics
+/// if the example does not exist.
+pub fn read(day: Day, example: Option<u8>) -> (String, String) {
+    let source = InputSource::Example(example);
+    let input = source.read(day).unwrap_or_else(|e| panic!("{e}"));
+    (source.path(day), input)
+}
+
+/// Compare the result of a part against its expected answer.
+/// # Panics
+/// with a diff of both values if they differ.
+#[track_caller]
+pub fn check<T: PartialEq + Debug>(path: &str, result: &T, expected: &T) {
+    if result != expected {
+        panic!(
+            "wrong answer for example \"{path}\".\n{}",
+            diff(&format!("{expected:?}"), &format!("{result:?}"))
+        );
+    }
+}
+
+/// Format the expected and actual value of a part below each other.
+/// Values that span multiple lines, e.g. ASCII art, are compared line by line instead.
+fn diff(expected: &str, actual: &str) -> String {
+    if !expected.contains("\\n") && !actual.contains("\\n") {
+        return format!("expected: {expected}\n  actual: {actual}");
+    }
+
+    let expected: Vec<&str> = expected.split("\\n").collect();
+    let actual: Vec<&str> = actual.split("\\n").collect();
+    let mut lines = vec!["--- expected\n+++ actual".to_string()];
+
+    for i in 0..expected.len().max(actual.len()) {
+        match (expected.get(i), actual.get(i)) {
+            (Some(a), Some(b)) if a == b => lines.push(format!("  {a}")),
+            (a, b) => {
+                lines.extend(a.map(|a| format!("- {a}")));
+                lines.extend(b.map(|b| format!("+ {b}")));
+            }
+        }
+    }
+
+    lines.join("\n")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{check, diff};
+
+    #[test]
+    fn formats_values() {
+        assert_eq!(
+            diff("Some(40)", "Some(41)"),
+            "expected: Some(40)\n  actual: Some(41)"
+        );
+    }
+
+    #[test]
+    fn diffs_lines() {
+        assert_eq!(
+            diff("Some(\"#.\\n.#\\n##\")", "Some(\"#.\\n##\")"),
+            "--- expected\n+++ actual\n  Some(\"#.\n- .#\n+ ##\")\n- ##\")"
+        );
+    }
+
+    #[test]
+    fn accepts_matching_answers() {
+        check("05.txt", &Some(42), &Some(42));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "wrong answer for example \"05.txt\".\nexpected: None\n  actual: Some(1)"
+    )]
+    fn rejects_wrong_answers() {
+        check("05.txt", &Some(1), &None);
+    }
+}
@@ -0,0 +1,178 @@
+/// Extracts the examples from puzzle pages, so they don't need to be copied by hand.
+use std::{fs, io, path::Path};
+
+use crate::template::backend::PuzzlePage;
+use crate::template::markdown::{extract_articles, strip_tags};
+use crate::template::{Day, Year};
+
+/// The example of a part: the first code block of its description and the last emphasized code value.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Example {
+    pub input: Option<String>,
+    pub answer: Option<String>,
+}
+
+/// Extract the examples of both parts from a puzzle page, downloaded by either backend.
+/// The example of part two is empty until part one is solved.
+pub fn extract(page: &PuzzlePage) -> [Example; 2] {
+    match page {
+        PuzzlePage::Html(page) => extract_html(page),
+        PuzzlePage::Markdown(page) => extract_markdown(page),
+    }
+}
+
+fn extract_html(page: &str) -> [Example; 2] {
+    let mut examples: [Example; 2] = Default::default();
+
+    for (example, article) in examples.iter_mut().zip(extract_articles(page)) {
+        *example = Example {
+            input: first_between(&article, "<pre><code>", "</code></pre>").map(strip_tags),
+            answer: last_between(&article, "<code><em>", "</em></code>")
+                .map(|x| strip_tags(x).trim().to_string()),
+        };
+    }
+
+    examples
+}
+
+/// Extract the examples from the Markdown written by aoc-cli:
+/// code blocks are fenced with "```", and emphasized code is written as "`*42*`" or "*`42`*".
+fn extract_markdown(page: &str) -> [Example; 2] {
+    let parts = match page.find("--- Part Two ---") {
+        Some(index) => [&page[..index], &page[index..]],
+        None => [page, ""],
+    };
+
+    parts.map(|part| Example {
+        input: first_between(part, "```", "```")
+            .and_then(|block| block.split_once('\n'))
+            .map(|(_, input)| input.to_string()),
+        answer: [("`*", "*`"), ("*`", "`*")]
+            .into_iter()
+            .filter_map(|(start, end)| Some((part.rfind(start)?, last_between(part, start, end)?)))
+            .max()
+            .map(|(_, answer)| answer.trim_matches('*').trim().to_string()),
+    })
+}
+
+/// Whether part two has an example that differs from the example of part one.
+pub fn has_separate_part_two(examples: &[Example; 2]) -> bool {
+    examples[1].input.is_some() && examples[1].input != examples[0].input
+}
+
+/// Write the examples to `DD.txt` and, if part two has its own example, `DD-2.txt`.
+/// Files that already have content are not touched. Returns the paths of written files.
+pub fn write(day: Day, examples: &[Example; 2]) -> Result<Vec<String>, io::Error> {
+    let dir = format!("{}/examples", Year::current().data_dir());
+    let mut written = vec![];
+
+    let files = [
+        (format!("{dir}/{day}.txt"), examples[0].input.as_ref()),
+        (
+            format!("{dir}/{day}-2.txt"),
+            examples[1]
+                .input
+                .as_ref()
+                .filter(|_| has_separate_part_two(examples)),
+        ),
+    ];
+
+    for (path, input) in files {
+        let Some(input) = input else {
+            continue;
+        };
+
+        let has_content = fs::read_to_string(&path).is_ok_and(|x| !x.trim().is_empty());
+        if has_content {
+            continue;
+        }
+
+        if let Some(parent) = Path::new(&path).parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, input)?;
+        written.push(path);
+    }
+
+    Ok(written)
+}
+
+fn first_between<'a>(s: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let from = s.find(start)? + start.len();
+    let to = from + s[from..].find(end)?;
+    Some(&s[from..to])
+}
+
+fn last_between<'a>(s: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let from = s.rfind(start)? + start.len();
+    let to = from + s[from..].find(end)?;
+    Some(&s[from..to])
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Example, extract, has_separate_part_two};
+    use crate::template::backend::PuzzlePage;
+
+    fn extract_html(page: &str) -> [Example; 2] {
+        extract(&PuzzlePage::Html(page.into()))
+    }
+
+    const PART_ONE: &str = "<article class=\"day-desc\"><h2>--- Day 1 ---</h2><p>For example:</p><pre><code>3   4\n4   &lt;3\n</code></pre><p>Another block:</p><pre><code>ignored</code></pre><p>The total is <code>1</code> + <code>2</code> = <code><em>11</em></code>.</p></article>";
+
+    #[test]
+    fn extracts_part_one() {
+        let [part_one, part_two] = extract_html(PART_ONE);
+        assert_eq!(
+            part_one,
+            Example {
+                input: Some("3   4\n4   <3\n".into()),
+                answer: Some("11".into())
+            }
+        );
+        assert_eq!(part_two, Example::default());
+    }
+
+    #[test]
+    fn extracts_part_two() {
+        let page = format!(
+            "{PART_ONE}<p>Your puzzle answer was <code>42</code>.</p><article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2><p>Now:</p><pre><code>a\nb\n</code></pre><p>The result is <code><em>31</em></code>.</p></article>"
+        );
+        let examples = extract_html(&page);
+        assert_eq!(examples[1].input, Some("a\nb\n".into()));
+        assert_eq!(examples[1].answer, Some("31".into()));
+        assert!(has_separate_part_two(&examples));
+    }
+
+    #[test]
+    fn detects_shared_examples() {
+        let page = format!(
+            "{PART_ONE}<article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2><p>In the same example, the result is <code><em>31</em></code>.</p></article>"
+        );
+        let examples = extract_html(&page);
+        assert_eq!(examples[1].input, None);
+        assert!(!has_separate_part_two(&examples));
+    }
+
+    #[test]
+    fn extracts_markdown() {
+        let page = "\\--- Day 1: Test ---\n----------\n\nFor example:\n\n```\n3   4\n4   3\n```\n\nThe total is `1` + `2` = `*11*`.\n\nYour puzzle answer was `42`.\n\n\\--- Part Two ---\n----------\n\nThe result is *`31`*.\n";
+        let [part_one, part_two] = extract(&PuzzlePage::Markdown(page.into()));
+        assert_eq!(
+            part_one,
+            Example {
+                input: Some("3   4\n4   3\n".into()),
+                answer: Some("11".into())
+            }
+        );
+        assert_eq!(
+            part_two,
+            Example {
+                input: None,
+                answer: Some("31".into())
+            }
+        );
+    }
+}
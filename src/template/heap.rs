@@ -0,0 +1,181 @@
+/// Heap allocation statistics of solution parts, captured with DHAT when the `dhat-heap` feature is enabled.
+use std::collections::HashMap;
+use tinyjson::JsonValue;
+
+use crate::template::Day;
+
+/// Allocations made during a single run of a part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeapStats {
+    /// Number of bytes allocated in total.
+    pub total_bytes: u64,
+    /// Number of allocations in total.
+    pub total_blocks: u64,
+    /// Maximum number of bytes allocated at the same time.
+    pub peak_bytes: u64,
+}
+
+impl HeapStats {
+    /// Format the statistics as a single line, e.g. for printing below a part's result.
+    pub fn summary(&self) -> String {
+        format!(
+            "heap: {} in {} block{} · peak {}",
+            format_bytes(self.total_bytes),
+            self.total_blocks,
+            if self.total_blocks == 1 { "" } else { "s" },
+            format_bytes(self.peak_bytes)
+        )
+    }
+}
+
+/// Format a number of bytes with a binary unit, e.g. `1.5 KiB`.
+#[allow(clippy::cast_precision_loss)]
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+/// Run `func` and return its result together with the allocations it made.
+/// The profile of the run is written to `dhat-heap-<day>-<part>.json` for inspection with DHAT's viewer.
+#[cfg(feature = "dhat-heap")]
+#[allow(clippy::cast_possible_truncation)]
+pub fn profile<T>(day: Day, part: u8, func: impl FnOnce() -> T) -> (T, Option<HeapStats>) {
+    let _profiler = dhat::Profiler::builder()
+        .file_name(format!("dhat-heap-{day}-{part}.json"))
+        .build();
+
+    let result = func();
+    let stats = dhat::HeapStats::get();
+
+    (
+        result,
+        Some(HeapStats {
+            total_bytes: stats.total_bytes,
+            total_blocks: stats.total_blocks,
+            peak_bytes: stats.max_bytes as u64,
+        }),
+    )
+}
+
+/// Run `func`. Allocations are only recorded with the `dhat-heap` feature.
+#[cfg(not(feature = "dhat-heap"))]
+pub fn profile<T>(_day: Day, _part: u8, func: impl FnOnce() -> T) -> (T, Option<HeapStats>) {
+    (func(), None)
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&HeapStats> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &HeapStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "total_bytes".into(),
+            JsonValue::Number(value.total_bytes as f64),
+        );
+        map.insert(
+            "total_blocks".into(),
+            JsonValue::Number(value.total_blocks as f64),
+        );
+        map.insert(
+            "peak_bytes".into(),
+            JsonValue::Number(value.peak_bytes as f64),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for HeapStats {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected heap stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .map(|v| v as u64)
+                .ok_or(format!("Expected heap.{key} to be a number."))
+        };
+
+        Ok(HeapStats {
+            total_bytes: number("total_bytes")?,
+            total_blocks: number("total_blocks")?,
+            peak_bytes: number("peak_bytes")?,
+        })
+    }
+}
+
+/// Read optional heap stats from a JSON object, treating `null` and missing keys as `None`.
+pub(crate) fn heap_from_json(
+    json: &HashMap<String, JsonValue>,
+    key: &str,
+) -> Result<Option<HeapStats>, String> {
+    match json.get(key) {
+        None => Ok(None),
+        Some(v) if v.is_null() => Ok(None),
+        Some(v) => HeapStats::try_from(v).map(Some),
+    }
+}
+
+/// Write optional heap stats as JSON, using `null` for `None`.
+pub(crate) fn heap_to_json(stats: Option<&HeapStats>) -> JsonValue {
+    match stats {
+        Some(stats) => JsonValue::from(stats),
+        None => JsonValue::Null,
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{HeapStats, format_bytes};
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    fn summarizes_stats() {
+        let stats = HeapStats {
+            total_bytes: 276,
+            total_blocks: 3,
+            peak_bytes: 2048,
+        };
+        assert_eq!(stats.summary(), "heap: 276 B in 3 blocks · peak 2.0 KiB");
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let stats = HeapStats {
+            total_bytes: 1 << 40,
+            total_blocks: 12_345,
+            peak_bytes: 4096,
+        };
+        let json = tinyjson::JsonValue::from(&stats);
+        assert_eq!(HeapStats::try_from(&json).unwrap(), stats);
+    }
+}
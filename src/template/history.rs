@@ -0,0 +1,322 @@
+/// Module that keeps an append-only archive of stored benchmark runs.
+/// Every `cargo time --store` appends one entry (one JSON object per line) to the history file.
+use std::{
+    collections::HashMap,
+    env,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    process::Command,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::compare::part_nanos;
+use crate::template::timings::Timings;
+use crate::template::{ANSI_BOLD, ANSI_DIM, ANSI_GREEN, ANSI_RED, ANSI_RESET, Day, Year};
+
+fn get_history_path() -> String {
+    format!("./{}/timings_history.jsonl", Year::current().data_dir())
+}
+
+/// A single benchmark run, together with the environment it ran in.
+#[derive(Clone, Debug)]
+pub struct HistoryEntry {
+    /// Abbreviated hash of the checked out commit, suffixed with `-dirty` for uncommitted changes.
+    pub commit: Option<String>,
+    /// UTC date and time in ISO 8601 format.
+    pub date: String,
+    pub rustc: Option<String>,
+    pub machine: Option<String>,
+    pub timings: Timings,
+}
+
+impl HistoryEntry {
+    /// Create an entry for `timings`, capturing the current environment.
+    pub fn capture(timings: Timings) -> Self {
+        Self {
+            commit: git_commit(),
+            date: format_date(SystemTime::now()),
+            rustc: command_output("rustc", &["-V"]),
+            machine: machine_name(),
+            timings,
+        }
+    }
+}
+
+/// Append an entry to the history file.
+pub fn append(entry: &HistoryEntry) -> Result<(), io::Error> {
+    let line = JsonValue::from(entry)
+        .stringify()
+        .map_err(|e| io::Error::other(e.to_string()))?;
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(get_history_path())?;
+
+    writeln!(file, "{line}")
+}
+
+/// Read all entries from the history file. If not present, returns no entries.
+pub fn read_from_file() -> Result<Vec<HistoryEntry>, String> {
+    let Ok(content) = fs::read_to_string(get_history_path()) else {
+        return Ok(vec![]);
+    };
+
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let json = JsonValue::from_str(line).or(Err("history contains invalid JSON."))?;
+            HistoryEntry::try_from(&json)
+        })
+        .collect()
+}
+
+/// Print how the runtime of each part of `day` evolved over all entries.
+pub fn print_day(entries: &[HistoryEntry], day: Day) {
+    println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+    println!("------");
+
+    let timings: Vec<_> = entries
+        .iter()
+        .filter_map(|entry| {
+            let timing = entry.timings.data.iter().find(|t| t.day == day)?;
+            Some((entry, timing))
+        })
+        .collect();
+
+    if timings.is_empty() {
+        println!("No stored benchmarks.");
+        return;
+    }
+
+    println!(
+        "{ANSI_BOLD}{:<21} {:<14} {:<9} {:<12} {:>22} {:>22}{ANSI_RESET}",
+        "Date", "Commit", "rustc", "Machine", "Part 1", "Part 2"
+    );
+
+    let mut previous = None;
+
+    for (entry, timing) in timings {
+        let parts: Vec<String> = [1, 2]
+            .iter()
+            .map(|part| {
+                let formatted = match part {
+                    1 => timing.part_1.as_deref(),
+                    _ => timing.part_2.as_deref(),
+                }
+                .unwrap_or("-");
+
+                let delta = match (
+                    previous.and_then(|t| part_nanos(t, *part)),
+                    part_nanos(timing, *part),
+                ) {
+                    (Some(before), Some(after)) => {
+                        let percent = (after - before) / before * 100.0;
+                        let color = if percent > 0.0 { ANSI_RED } else { ANSI_GREEN };
+                        format!("{color}{percent:>+7.1}%{ANSI_RESET}")
+                    }
+                    _ => format!("{ANSI_DIM}{:>8}{ANSI_RESET}", "-"),
+                };
+
+                format!("{formatted:>12} {delta}")
+            })
+            .collect();
+
+        println!(
+            "{:<21} {:<14} {:<9} {:<12} {} {}",
+            entry.date,
+            entry.commit.as_deref().unwrap_or("-"),
+            // NOTE: `rustc -V` prints e.g. "rustc 1.83.0 (90b35a623 2024-11-26)".
+            entry
+                .rustc
+                .as_deref()
+                .and_then(|x| x.split_whitespace().nth(1))
+                .unwrap_or("-"),
+            entry.machine.as_deref().unwrap_or("-"),
+            parts[0],
+            parts[1]
+        );
+
+        previous = Some(timing);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn command_output(cmd: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(cmd).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8(output.stdout).ok()?;
+    let trimmed = stdout.trim();
+    (!trimmed.is_empty()).then(|| trimmed.to_string())
+}
+
+fn git_commit() -> Option<String> {
+    let commit = command_output("git", &["rev-parse", "--short", "HEAD"])?;
+    let is_dirty = command_output("git", &["status", "--porcelain"]).is_some();
+    Some(if is_dirty {
+        format!("{commit}-dirty")
+    } else {
+        commit
+    })
+}
+
+fn machine_name() -> Option<String> {
+    env::var("HOSTNAME")
+        .or_else(|_| env::var("COMPUTERNAME"))
+        .ok()
+        .or_else(|| command_output("hostname", &[]))
+}
+
+/// Format a point in time as an ISO 8601 UTC timestamp, e.g. `2024-12-01T05:00:00Z`.
+pub(crate) fn format_date(time: SystemTime) -> String {
+    let secs = time.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+    let (days, secs_of_day) = (secs / 86_400, secs % 86_400);
+    let (year, month, day) = civil_from_days(days);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        secs_of_day / 3600,
+        (secs_of_day % 3600) / 60,
+        secs_of_day % 60
+    )
+}
+
+/// Convert days since the unix epoch to a (year, month, day) date.
+/// See: https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+    (year, month, day)
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn optional_string(value: Option<&String>) -> JsonValue {
+    match value {
+        Some(x) => JsonValue::String(x.clone()),
+        None => JsonValue::Null,
+    }
+}
+
+impl From<&HistoryEntry> for JsonValue {
+    fn from(value: &HistoryEntry) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("commit".into(), optional_string(value.commit.as_ref()));
+        map.insert("date".into(), JsonValue::String(value.date.clone()));
+        map.insert("rustc".into(), optional_string(value.rustc.as_ref()));
+        map.insert("machine".into(), optional_string(value.machine.as_ref()));
+        map.insert("timings".into(), JsonValue::from(value.timings.clone()));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for HistoryEntry {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected history entry to be a JSON object.")?;
+
+        let optional = |key: &str| {
+            json.get(key)
+                .map(|v| if v.is_null() { None } else { v.get::<String>() })
+                .ok_or(format!("Expected entry.{key} to be null or string."))
+                .map(|v| v.cloned())
+        };
+
+        let date = json
+            .get("date")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected entry.date to be a string.")?;
+
+        let timings = Timings::try_from(
+            json.get("timings")
+                .ok_or("Expected entry.timings to be present.")?,
+        )?;
+
+        Ok(HistoryEntry {
+            commit: optional("commit")?,
+            date: date.clone(),
+            rustc: optional("rustc")?,
+            machine: optional("machine")?,
+            timings,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+    use tinyjson::JsonValue;
+
+    use super::{HistoryEntry, format_date};
+    use crate::{
+        day,
+        template::{test_support::timing, timings::Timings},
+    };
+
+    #[test]
+    fn formats_dates() {
+        assert_eq!(format_date(UNIX_EPOCH), "1970-01-01T00:00:00Z");
+        assert_eq!(
+            format_date(UNIX_EPOCH + Duration::from_secs(1_733_029_200)),
+            "2024-12-01T05:00:00Z"
+        );
+        assert_eq!(
+            format_date(UNIX_EPOCH + Duration::from_secs(951_782_400)),
+            "2000-02-29T00:00:00Z"
+        );
+    }
+
+    #[test]
+    fn roundtrips_entries() {
+        let entry = HistoryEntry {
+            commit: Some("abc1234".into()),
+            date: "2024-12-01T05:00:00Z".into(),
+            rustc: None,
+            machine: Some("workstation".into()),
+            timings: Timings {
+                data: vec![timing(day!(1), Some("1ms"), None, 1_000_000_f64)],
+            },
+        };
+
+        let json: JsonValue = JsonValue::from(&entry)
+            .stringify()
+            .unwrap()
+            .parse()
+            .unwrap();
+        let parsed = HistoryEntry::try_from(&json).unwrap();
+
+        assert_eq!(parsed.commit, entry.commit);
+        assert_eq!(parsed.date, entry.date);
+        assert_eq!(parsed.rustc, None);
+        assert_eq!(parsed.machine, entry.machine);
+        assert_eq!(parsed.timings.data.len(), 1);
+        assert_eq!(parsed.timings.data[0].part_1, Some("1ms".into()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_entries_without_timings() {
+        let json: JsonValue = r#"{ "date": "2024-12-01T05:00:00Z" }"#.parse().unwrap();
+        HistoryEntry::try_from(&json).unwrap();
+    }
+}
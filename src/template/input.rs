@@ -0,0 +1,195 @@
+/// Selects the input a solution runs against: the puzzle input, a team member's input, a file, stdin or an example.
+use std::{
+    env,
+    fmt::Display,
+    fs,
+    io::{Read, stdin},
+    path::Path,
+};
+
+use crate::template::{Day, Year, read_file, read_file_named, read_file_part, team};
+
+/// Input of a solution, selected via `--member <NAME>`, `--input <PATH>`, `--stdin` or `--example [N]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// The day's puzzle input in `data/inputs` (default).
+    Puzzle,
+    /// The puzzle input of a team member in the day's directory, e.g. `data/inputs/05/alice.txt`.
+    Member(String),
+    /// A file at an arbitrary path.
+    File(String),
+    /// Everything read from stdin.
+    Stdin,
+    /// The day's example in `data/examples`, or one of its numbered examples, e.g. `05-2.txt`.
+    Example(Option<u8>),
+}
+
+impl InputSource {
+    /// Reads the input source from the command-line arguments of the current process.
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+        Self::parse(&args)
+    }
+
+    /// Reads the input source from a list of command-line arguments.
+    pub fn parse(args: &[String]) -> Self {
+        let position = |name: &str| args.iter().position(|x| x == name);
+
+        if let Some(index) = position("--member")
+            && let Some(member) = args.get(index + 1)
+        {
+            InputSource::Member(member.clone())
+        } else if let Some(index) = position("--input")
+            && let Some(path) = args.get(index + 1)
+        {
+            InputSource::File(path.clone())
+        } else if position("--stdin").is_some() {
+            InputSource::Stdin
+        } else if let Some(index) = position("--example") {
+            InputSource::Example(args.get(index + 1).and_then(|x| x.parse().ok()))
+        } else {
+            InputSource::Puzzle
+        }
+    }
+
+    /// Arguments that forward the input source to a solution binary.
+    pub fn to_args(&self) -> Vec<String> {
+        match self {
+            InputSource::Puzzle => vec![],
+            InputSource::Member(member) => vec!["--member".into(), member.clone()],
+            InputSource::File(path) => vec!["--input".into(), path.clone()],
+            InputSource::Stdin => vec!["--stdin".into()],
+            InputSource::Example(None) => vec!["--example".into()],
+            InputSource::Example(Some(n)) => vec!["--example".into(), n.to_string()],
+        }
+    }
+
+    pub fn is_puzzle(&self) -> bool {
+        *self == InputSource::Puzzle
+    }
+
+    /// Read the input of a day.
+    pub fn read(&self, day: Day) -> Result<String, String> {
+        match self {
+            InputSource::File(path) => {
+                fs::read_to_string(path).map_err(|e| format!("could not read \"{path}\": {e}"))
+            }
+            InputSource::Stdin => {
+                let mut input = String::new();
+                stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| format!("could not read stdin: {e}"))?;
+                Ok(input)
+            }
+            InputSource::Puzzle | InputSource::Member(_) | InputSource::Example(_) => {
+                let path = self.path(day);
+                if !Path::new(&path).exists() {
+                    if self.is_puzzle() && !team::members(day).is_empty() {
+                        return Err(format!(
+                            "the inputs of day {day} belong to team members, pass `--member <NAME>`."
+                        ));
+                    }
+                    return Err(format!("could not find \"{path}\"."));
+                }
+
+                Ok(match self {
+                    InputSource::Member(member) => read_file_named("inputs", day, member),
+                    InputSource::Example(Some(n)) => read_file_part("examples", day, *n),
+                    InputSource::Example(None) => read_file("examples", day),
+                    _ => read_file("inputs", day),
+                })
+            }
+        }
+    }
+
+    /// Path of the input file of a day, relative to the repository root. `stdin` for [`InputSource::Stdin`].
+    pub fn path(&self, day: Day) -> String {
+        let data_dir = Year::current().data_dir();
+
+        match self {
+            InputSource::Puzzle => format!("{data_dir}/inputs/{day}.txt"),
+            InputSource::Member(member) => format!("{data_dir}/inputs/{day}/{member}.txt"),
+            InputSource::File(path) => path.clone(),
+            InputSource::Stdin => "stdin".into(),
+            InputSource::Example(None) => format!("{data_dir}/examples/{day}.txt"),
+            InputSource::Example(Some(n)) => format!("{data_dir}/examples/{day}-{n}.txt"),
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::Puzzle => write!(f, "puzzle input"),
+            InputSource::Member(member) => write!(f, "input of {member}"),
+            InputSource::File(_) => write!(f, "file"),
+            InputSource::Stdin => write!(f, "stdin"),
+            InputSource::Example(None) => write!(f, "example"),
+            InputSource::Example(Some(n)) => write!(f, "example {n}"),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::InputSource;
+    use crate::day;
+
+    fn parse(args: &[&str]) -> InputSource {
+        let args: Vec<String> = args.iter().map(ToString::to_string).collect();
+        InputSource::parse(&args)
+    }
+
+    #[test]
+    fn parses_input_sources() {
+        assert_eq!(parse(&["05", "--format", "ndjson"]), InputSource::Puzzle);
+        assert_eq!(
+            parse(&["05", "--input", "edge.txt"]),
+            InputSource::File("edge.txt".into())
+        );
+        assert_eq!(parse(&["05", "--stdin"]), InputSource::Stdin);
+        assert_eq!(
+            parse(&["05", "--member", "alice"]),
+            InputSource::Member("alice".into())
+        );
+        assert_eq!(parse(&["05", "--example"]), InputSource::Example(None));
+        assert_eq!(
+            parse(&["05", "--example", "--submit", "1"]),
+            InputSource::Example(None)
+        );
+        assert_eq!(
+            parse(&["05", "--example", "2"]),
+            InputSource::Example(Some(2))
+        );
+    }
+
+    #[test]
+    fn roundtrips_args() {
+        for source in [
+            InputSource::Puzzle,
+            InputSource::Member("alice".into()),
+            InputSource::File("a b.txt".into()),
+            InputSource::Stdin,
+            InputSource::Example(None),
+            InputSource::Example(Some(3)),
+        ] {
+            let mut args = vec!["05".to_string()];
+            args.extend(source.to_args());
+            assert_eq!(InputSource::parse(&args), source);
+        }
+    }
+
+    #[test]
+    fn formats_paths() {
+        assert_eq!(
+            InputSource::Example(Some(2)).path(day!(5)),
+            format!(
+                "{}/examples/05-2.txt",
+                crate::template::Year::current().data_dir()
+            )
+        );
+        assert_eq!(InputSource::Stdin.path(day!(5)), "stdin");
+    }
+}
@@ -0,0 +1,248 @@
+/// Wall-clock timeouts and memory limits for running solutions.
+///
+/// Limits are configured by rules of the form `[DAY[.PART]=]VALUE`, e.g. `30` applies to all parts of all days,
+/// `12=60` to all parts of day 12 and `12.2=120` to part 2 of day 12. The most specific rule wins.
+/// The shared parse step of a solution is limited by the rules for its day.
+use std::{env, process, str::FromStr, time::Duration};
+
+use crate::template::Day;
+
+const MEGABYTE: u64 = 1024 * 1024;
+
+/// A single limit rule, see the module documentation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LimitRule {
+    pub day: Option<Day>,
+    pub part: Option<u8>,
+    pub value: u64,
+}
+
+impl LimitRule {
+    /// How specific the rule is for a part of a day, or `None` if it does not apply.
+    fn specificity(&self, day: Day, part: u8) -> Option<u8> {
+        match (self.day, self.part) {
+            (None, _) => Some(0),
+            (Some(d), None) if d == day => Some(1),
+            (Some(d), Some(p)) if d == day && p == part => Some(2),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for LimitRule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (target, value) = match s.split_once('=') {
+            Some((target, value)) => (Some(target), value),
+            None => (None, s),
+        };
+
+        let value = value
+            .trim()
+            .parse()
+            .map_err(|_| format!("invalid limit \"{s}\", expected a number."))?;
+
+        let (day, part) = match target.map(|x| x.split_once('.').unwrap_or((x, ""))) {
+            None => (None, None),
+            Some((day, part)) => {
+                let day = day
+                    .trim()
+                    .parse::<Day>()
+                    .map_err(|e| format!("invalid limit \"{s}\": {e}"))?;
+                let part = match part.trim() {
+                    "" => None,
+                    "1" => Some(1),
+                    "2" => Some(2),
+                    x => return Err(format!("invalid limit \"{s}\": unknown part \"{x}\".")),
+                };
+                (Some(day), part)
+            }
+        };
+
+        Ok(LimitRule { day, part, value })
+    }
+}
+
+impl std::fmt::Display for LimitRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.day, self.part) {
+            (None, _) => write!(f, "{}", self.value),
+            (Some(day), None) => write!(f, "{day}={}", self.value),
+            (Some(day), Some(part)) => write!(f, "{day}.{part}={}", self.value),
+        }
+    }
+}
+
+/// Limits for running solutions, passed with `--timeout <SECS>` and `--memory-limit <MB>`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Limits {
+    /// Wall-clock timeouts in seconds.
+    pub timeouts: Vec<LimitRule>,
+    /// Address-space limits in megabytes.
+    pub memory_limits: Vec<LimitRule>,
+}
+
+impl Limits {
+    /// Reads the memory limits forwarded to a solution binary from the command-line arguments of the current process.
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+
+        let memory_limits = args
+            .windows(2)
+            .filter(|x| x[0] == "--memory-limit")
+            .map(|x| x[1].parse())
+            .collect::<Result<_, String>>()
+            .unwrap_or_else(|e| {
+                eprintln!("{e}");
+                process::exit(1);
+            });
+
+        Limits {
+            timeouts: vec![],
+            memory_limits,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.timeouts.is_empty() && self.memory_limits.is_empty()
+    }
+
+    /// The timeout of a part. Part `0` is the shared parse step.
+    pub fn timeout(&self, day: Day, part: u8) -> Option<Duration> {
+        resolve(&self.timeouts, day, part).map(Duration::from_secs)
+    }
+
+    /// The memory limit of a part in bytes. Part `0` is the shared parse step.
+    pub fn memory_limit(&self, day: Day, part: u8) -> Option<u64> {
+        resolve(&self.memory_limits, day, part).map(|x| x.saturating_mul(MEGABYTE))
+    }
+
+    /// Arguments that forward the memory limits to a solution binary.
+    /// Timeouts are enforced by the parent process and are not forwarded.
+    pub fn to_args(&self) -> Vec<String> {
+        self.memory_limits
+            .iter()
+            .flat_map(|rule| ["--memory-limit".into(), rule.to_string()])
+            .collect()
+    }
+}
+
+fn resolve(rules: &[LimitRule], day: Day, part: u8) -> Option<u64> {
+    rules
+        .iter()
+        .filter_map(|rule| Some((rule.specificity(day, part)?, rule.value)))
+        .max_by_key(|(specificity, _)| *specificity)
+        .map(|(_, value)| value)
+}
+
+/// Limit the address space of the current process to `bytes`, or lift the limit with `None`.
+/// Allocations beyond the limit fail, which aborts the process.
+pub fn apply_memory_limit(bytes: Option<u64>) -> Result<(), String> {
+    sys::set_address_space_limit(bytes)
+}
+
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+mod sys {
+    pub fn set_address_space_limit(bytes: Option<u64>) -> Result<(), String> {
+        let mut limit = libc::rlimit {
+            rlim_cur: 0,
+            rlim_max: 0,
+        };
+
+        // SAFETY: `limit` is a valid, writable `struct rlimit`.
+        if unsafe { libc::getrlimit(libc::RLIMIT_AS, &mut limit) } != 0 {
+            return Err("could not read the memory limit.".into());
+        }
+
+        // the soft limit can be changed freely up to the hard limit.
+        limit.rlim_cur = bytes.map_or(limit.rlim_max, |bytes| bytes.min(limit.rlim_max));
+
+        // SAFETY: `limit` is a valid `struct rlimit`.
+        if unsafe { libc::setrlimit(libc::RLIMIT_AS, &limit) } != 0 {
+            return Err("could not set the memory limit.".into());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+mod sys {
+    pub fn set_address_space_limit(bytes: Option<u64>) -> Result<(), String> {
+        match bytes {
+            Some(_) => Err("memory limits are not supported on this platform.".into()),
+            None => Ok(()),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{LimitRule, Limits};
+    use crate::day;
+
+    #[test]
+    fn parses_rules() {
+        assert_eq!(
+            "30".parse(),
+            Ok(LimitRule {
+                day: None,
+                part: None,
+                value: 30
+            })
+        );
+        assert_eq!(
+            "12=60".parse(),
+            Ok(LimitRule {
+                day: Some(day!(12)),
+                part: None,
+                value: 60
+            })
+        );
+        assert_eq!(
+            "12.2=120".parse(),
+            Ok(LimitRule {
+                day: Some(day!(12)),
+                part: Some(2),
+                value: 120
+            })
+        );
+        assert!("12.3=1".parse::<LimitRule>().is_err());
+        assert!("abc".parse::<LimitRule>().is_err());
+    }
+
+    #[test]
+    fn resolves_most_specific_rule() {
+        let limits = Limits {
+            timeouts: ["12.2=120", "30", "12=60"]
+                .iter()
+                .map(|x| x.parse().unwrap())
+                .collect(),
+            memory_limits: vec!["5.1=2".parse().unwrap()],
+        };
+
+        assert_eq!(limits.timeout(day!(1), 1), Some(Duration::from_secs(30)));
+        assert_eq!(limits.timeout(day!(12), 1), Some(Duration::from_secs(60)));
+        assert_eq!(limits.timeout(day!(12), 2), Some(Duration::from_secs(120)));
+        assert_eq!(limits.memory_limit(day!(5), 1), Some(2 * 1024 * 1024));
+        assert_eq!(limits.memory_limit(day!(5), 2), None);
+    }
+
+    #[test]
+    fn forwards_memory_limits() {
+        let limits = Limits {
+            timeouts: vec!["30".parse().unwrap()],
+            memory_limits: vec!["512".parse().unwrap(), "12.2=1024".parse().unwrap()],
+        };
+
+        assert_eq!(
+            limits.to_args(),
+            ["--memory-limit", "512", "--memory-limit", "12.2=1024"]
+        );
+    }
+}
@@ -0,0 +1,394 @@
+/// Renders puzzle pages of the Advent of Code website to Markdown.
+/// Only the small subset of HTML used in puzzle descriptions is supported.
+use std::iter;
+
+const BASE_URL: &str = "https://adventofcode.com";
+
+enum Token<'a> {
+    Open { name: String, attrs: &'a str },
+    Close { name: String },
+    Text(&'a str),
+}
+
+/// Render the puzzle descriptions of a page to Markdown.
+/// Every `<article>` element (one per unlocked part) is rendered. If the page has none, the whole page is rendered.
+pub fn render(page: &str) -> String {
+    let articles = extract_articles(page);
+
+    let blocks: Vec<String> = if articles.is_empty() {
+        vec![render_fragment(page)]
+    } else {
+        articles
+            .iter()
+            .map(|article| render_fragment(article))
+            .collect()
+    };
+
+    format!("{}\n", blocks.join("\n\n"))
+}
+
+/// Extract the inner HTML of all `<article>` elements of a page.
+pub fn extract_articles(page: &str) -> Vec<String> {
+    let mut articles = vec![];
+    let mut rest = page;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(content_start) = rest[start..].find('>').map(|x| start + x + 1) else {
+            break;
+        };
+        let Some(end) = rest[content_start..]
+            .find("</article>")
+            .map(|x| content_start + x)
+        else {
+            break;
+        };
+
+        articles.push(rest[content_start..end].to_string());
+        rest = &rest[end + "</article>".len()..];
+    }
+
+    articles
+}
+
+/// Extract the title of a puzzle page, e.g. "Day 1: Historian Hysteria".
+pub fn title(page: &str) -> Option<String> {
+    let start = page.find("<h2")?;
+    let start = start + page[start..].find('>')? + 1;
+    let end = start + page[start..].find("</h2>")?;
+
+    let title = strip_tags(&page[start..end]);
+    let title = title.trim().trim_matches('-').trim();
+    (!title.is_empty()).then(|| title.to_string())
+}
+
+/// Remove all tags from a HTML fragment, leaving only its text.
+pub fn strip_tags(html: &str) -> String {
+    tokenize(html)
+        .filter_map(|token| match token {
+            Token::Text(text) => Some(decode_entities(text)),
+            _ => None,
+        })
+        .collect()
+}
+
+fn render_fragment(html: &str) -> String {
+    let mut out = String::new();
+    let mut in_pre = false;
+    let mut in_code = false;
+    let mut list_depth: usize = 0;
+    let mut links: Vec<String> = vec![];
+
+    for token in tokenize(html) {
+        match token {
+            Token::Text(text) => {
+                let text = decode_entities(text);
+
+                if in_pre {
+                    out.push_str(&text);
+                    continue;
+                }
+
+                // collapse whitespace, but keep it between inline elements.
+                let is_separated = out.is_empty() || out.ends_with(char::is_whitespace);
+                if text.starts_with(char::is_whitespace) && !is_separated {
+                    out.push(' ');
+                }
+                out.push_str(&text.split_whitespace().collect::<Vec<_>>().join(" "));
+                if text.ends_with(char::is_whitespace) && !text.trim().is_empty() {
+                    out.push(' ');
+                }
+            }
+            Token::Open { name, attrs } => match name.as_str() {
+                "h2" => {
+                    block_break(&mut out);
+                    out.push_str("## ");
+                }
+                "p" => block_break(&mut out),
+                "pre" => {
+                    block_break(&mut out);
+                    out.push_str("```\n");
+                    in_pre = true;
+                }
+                "code" if !in_pre => {
+                    out.push('`');
+                    in_code = true;
+                }
+                "em" if !in_pre => out.push('*'),
+                "ul" | "ol" => {
+                    if list_depth == 0 {
+                        block_break(&mut out);
+                    }
+                    list_depth += 1;
+                }
+                "li" => {
+                    line_break(&mut out);
+                    out.push_str(&"  ".repeat(list_depth.saturating_sub(1)));
+                    out.push_str("- ");
+                }
+                "a" if !in_pre && !in_code => {
+                    out.push('[');
+                    links.push(attribute(attrs, "href").unwrap_or_default());
+                }
+                "br" => out.push('\n'),
+                _ => {}
+            },
+            Token::Close { name } => match name.as_str() {
+                "h2" | "p" => block_break(&mut out),
+                "pre" => {
+                    line_break(&mut out);
+                    out.push_str("```");
+                    block_break(&mut out);
+                    in_pre = false;
+                }
+                "code" if !in_pre => {
+                    out.push('`');
+                    in_code = false;
+                }
+                "em" if !in_pre => out.push('*'),
+                "ul" | "ol" => {
+                    list_depth = list_depth.saturating_sub(1);
+                    if list_depth == 0 {
+                        block_break(&mut out);
+                    }
+                }
+                "a" if !in_pre && !in_code => {
+                    let href = links.pop().unwrap_or_default();
+                    if href.starts_with('/') {
+                        out.push_str(&format!("]({BASE_URL}{href})"));
+                    } else {
+                        out.push_str(&format!("]({href})"));
+                    }
+                }
+                _ => {}
+            },
+        }
+    }
+
+    normalize(&out)
+}
+
+/// Split HTML into tags and text. Comments are skipped.
+fn tokenize(html: &str) -> impl Iterator<Item = Token<'_>> {
+    let mut rest = html;
+
+    iter::from_fn(move || {
+        loop {
+            if rest.is_empty() {
+                return None;
+            }
+
+            if let Some(comment) = rest.strip_prefix("<!--") {
+                rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+                continue;
+            }
+
+            if rest.starts_with('<')
+                && let Some(end) = rest.find('>')
+            {
+                let tag = &rest[1..end];
+                rest = &rest[end + 1..];
+
+                let (is_close, tag) = match tag.strip_prefix('/') {
+                    Some(tag) => (true, tag),
+                    None => (false, tag),
+                };
+                let name_end = tag
+                    .find(|c: char| !c.is_ascii_alphanumeric())
+                    .unwrap_or(tag.len());
+                let name = tag[..name_end].to_ascii_lowercase();
+
+                return Some(if is_close {
+                    Token::Close { name }
+                } else {
+                    Token::Open {
+                        name,
+                        attrs: &tag[name_end..],
+                    }
+                });
+            }
+
+            // a `<` without a closing `>` is text.
+            let start = usize::from(rest.starts_with('<'));
+            let end = rest[start..].find('<').map_or(rest.len(), |x| start + x);
+            let text = &rest[..end];
+            rest = &rest[end..];
+            return Some(Token::Text(text));
+        }
+    })
+}
+
+fn attribute(attrs: &str, name: &str) -> Option<String> {
+    let start = attrs.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = start + attrs[start..].find('"')?;
+    Some(decode_entities(&attrs[start..end]))
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest
+            .find(';')
+            .filter(|end| *end <= 10)
+            .and_then(|end| Some((decode_entity(&rest[1..end])?, end)));
+
+        match entity {
+            Some((c, end)) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+fn decode_entity(entity: &str) -> Option<char> {
+    match entity {
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "amp" => Some('&'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some(' '),
+        _ => {
+            let code = match entity.strip_prefix("#x") {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => entity.strip_prefix('#')?.parse().ok()?,
+            };
+            char::from_u32(code)
+        }
+    }
+}
+
+/// End the current block with an empty line, unless it is already ended.
+fn block_break(out: &mut String) {
+    trim_trailing_spaces(out);
+    if !out.is_empty() && !out.ends_with("\n\n") {
+        out.push_str(if out.ends_with('\n') { "\n" } else { "\n\n" });
+    }
+}
+
+/// End the current line, unless it is already ended.
+fn line_break(out: &mut String) {
+    trim_trailing_spaces(out);
+    if !out.is_empty() && !out.ends_with('\n') {
+        out.push('\n');
+    }
+}
+
+fn trim_trailing_spaces(out: &mut String) {
+    while out.ends_with(' ') {
+        out.pop();
+    }
+}
+
+/// Trim trailing whitespace of every line and collapse consecutive empty lines.
+fn normalize(markdown: &str) -> String {
+    let mut lines: Vec<&str> = vec![];
+
+    for line in markdown.lines().map(str::trim_end) {
+        if line.is_empty() && lines.last().is_none_or(|last| last.is_empty()) {
+            continue;
+        }
+        lines.push(line);
+    }
+
+    lines.join("\n").trim_end().to_string()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{extract_articles, render, strip_tags, title};
+
+    #[test]
+    fn extracts_articles() {
+        let page = r#"<main><article class="day-desc"><h2>Day 1</h2></article><p>x</p><article><p>Part two</p></article></main>"#;
+        assert_eq!(
+            extract_articles(page),
+            vec!["<h2>Day 1</h2>", "<p>Part two</p>"]
+        );
+        assert!(extract_articles("<main></main>").is_empty());
+    }
+
+    #[test]
+    fn extracts_titles() {
+        assert_eq!(
+            title(
+                r#"<main><article class="day-desc"><h2>--- Day 1: Historian Hysteria ---</h2></article></main>"#
+            ),
+            Some("Day 1: Historian Hysteria".into())
+        );
+        assert_eq!(title("<main></main>"), None);
+    }
+
+    #[test]
+    fn strips_tags() {
+        assert_eq!(
+            strip_tags("<p>That&apos;s <em>not</em> the right answer.</p>"),
+            "That's not the right answer."
+        );
+    }
+
+    #[test]
+    fn keeps_unclosed_brackets() {
+        assert_eq!(strip_tags("a <"), "a <");
+        assert_eq!(strip_tags("<b>1</b> <é"), "1 <é");
+    }
+
+    #[test]
+    fn renders_headings_and_paragraphs() {
+        let page = "<html><body><main>\n<article class=\"day-desc\"><h2>--- Day 1: Test ---</h2><p>The first\n   paragraph.</p>\n<p>The second one.</p>\n</article>\n<p>Ignored.</p></main></body></html>";
+        assert_eq!(
+            render(page),
+            "## --- Day 1: Test ---\n\nThe first paragraph.\n\nThe second one.\n"
+        );
+    }
+
+    #[test]
+    fn renders_inline_elements() {
+        let page = r#"<article><p>What is <em>the sum</em> of <code>a &lt; b</code>? It is <code><em>42</em></code>, see <a href="/2024/about">here</a>.</p></article>"#;
+        assert_eq!(
+            render(page),
+            "What is *the sum* of `a < b`? It is `*42*`, see [here](https://adventofcode.com/2024/about).\n"
+        );
+    }
+
+    #[test]
+    fn preserves_code_blocks() {
+        let page = "<article><p>For example:</p>\n<pre><code>3   4\n4   <em>3</em>\n</code></pre>\n<p>Done.</p></article>";
+        assert_eq!(
+            render(page),
+            "For example:\n\n```\n3   4\n4   3\n```\n\nDone.\n"
+        );
+    }
+
+    #[test]
+    fn renders_lists() {
+        let page = "<article><p>Steps:</p><ul><li>First <code>1</code>.</li><li>Second.</li></ul><p>After.</p></article>";
+        assert_eq!(
+            render(page),
+            "Steps:\n\n- First `1`.\n- Second.\n\nAfter.\n"
+        );
+    }
+
+    #[test]
+    fn renders_both_parts() {
+        let page = r#"<article class="day-desc"><h2>--- Day 2 ---</h2><p>One.</p></article><p>Your answer was <code>1</code>.</p><article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Two.</p></article>"#;
+        assert_eq!(
+            render(page),
+            "## --- Day 2 ---\n\nOne.\n\n## --- Part Two ---\n\nTwo.\n"
+        );
+    }
+}
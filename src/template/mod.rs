@@ -1,25 +1,61 @@
 use std::{env, fs};
 
+pub mod answer;
+pub mod answers;
 pub mod aoc_cli;
+pub mod aoc_client;
+pub mod backend;
+pub mod bench;
 pub mod commands;
+pub mod example_tests;
+pub mod heap;
+pub mod input;
+pub mod limits;
+pub mod ocr;
+pub mod params;
+pub mod registry;
+pub mod report;
 pub mod runner;
+pub mod team;
 
+pub use answer::Answer;
 pub use day::*;
+pub use year::*;
 
+mod compare;
 mod day;
+mod examples;
+mod history;
+mod markdown;
 mod readme_benchmarks;
 mod run_multi;
+mod submissions;
+mod table;
+#[cfg(feature = "test_lib")]
+mod test_support;
 mod timings;
+mod year;
+
+#[cfg(all(feature = "registry", feature = "dhat-heap"))]
+compile_error!("the `registry` and `dhat-heap` features can not be enabled at the same time.");
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
+pub const ANSI_DIM: &str = "\x1b[2m";
+pub const ANSI_RED: &str = "\x1b[31m";
+pub const ANSI_GREEN: &str = "\x1b[32m";
 pub const ANSI_RESET: &str = "\x1b[0m";
+/// Clears the terminal and moves the cursor to the top left corner.
+pub const ANSI_CLEAR: &str = "\x1b[2J\x1b[H";
 
 /// Helper function that reads a text file to a string.
 #[must_use]
 pub fn read_file(folder: &str, day: Day) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let filepath = cwd
+        .join(Year::current().data_dir())
+        .join(folder)
+        .join(format!("{day}.txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
@@ -29,40 +65,202 @@ pub fn read_file(folder: &str, day: Day) -> String {
 pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
-        .join("data")
+        .join(Year::current().data_dir())
         .join(folder)
         .join(format!("{day}-{part}.txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
+/// Helper function that reads a named text file from a day's directory to string. E.g. like `01/alice.txt`.
+#[must_use]
+pub fn read_file_named(folder: &str, day: Day, name: &str) -> String {
+    let cwd = env::current_dir().unwrap();
+    let filepath = cwd
+        .join(Year::current().data_dir())
+        .join(folder)
+        .join(day.to_string())
+        .join(format!("{name}.txt"));
+    let f = fs::read_to_string(filepath);
+    f.expect("could not open input file")
+}
+
 /// Creates the constant `DAY` and sets up the input and runner for each part.
 ///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+///
+/// Parts can return an `Option` or a `Result`, see [`answer::IntoAnswer`].
+///
+/// The generated `main` runs against the puzzle input, or the input selected with `--input <PATH>`,
+/// `--stdin` or `--example [N]`, see [`input::InputSource`].
+///
+/// With `parse = <fn>`, the input is parsed once by the given function and a reference to its output
+/// is passed to both parts. The parse step is timed separately from the parts.
+///
+/// With `params = [<name> { default: <value>, example: <value> }, ..]`, the solution declares parameters
+/// that differ between the puzzle input and the examples, read with [`param!`]. The example value is optional.
+/// Values can be overridden with `--param <NAME>=<VALUE>`, see [`params`].
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+    ($day:expr $(, params = $params:tt)?) => {
+        $crate::solution!(@impl $day, [$($params)?], [part_one, 1] [part_two, 2]);
+    };
+    ($day:expr, 1 $(, params = $params:tt)?) => {
+        $crate::solution!(@impl $day, [$($params)?], [part_one, 1]);
+    };
+    ($day:expr, 2 $(, params = $params:tt)?) => {
+        $crate::solution!(@impl $day, [$($params)?], [part_two, 2]);
     };
-    ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+    ($day:expr, parse = $parse:expr $(, params = $params:tt)?) => {
+        $crate::solution!(@impl $day, [$($params)?], parse = $parse, [part_one, 1] [part_two, 2]);
     };
-    ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+    ($day:expr, 1, parse = $parse:expr $(, params = $params:tt)?) => {
+        $crate::solution!(@impl $day, [$($params)?], parse = $parse, [part_one, 1]);
     };
+    ($day:expr, 2, parse = $parse:expr $(, params = $params:tt)?) => {
+        $crate::solution!(@impl $day, [$($params)?], parse = $parse, [part_two, 2]);
+    };
+
+    (@impl $day:expr, $params:tt, parse = $parse:expr, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@common $day, $params);
+
+        /// The current day's parts, compiled into the main binary with the `registry` feature.
+        #[allow(dead_code)]
+        pub const SOLUTION: $crate::template::registry::Solution =
+            $crate::template::registry::Solution {
+                day: DAY,
+                runner: |input, bench_config| {
+                    use $crate::template::runner::*;
+                    let _ = $crate::template::params::install(PARAMS, false, &[]);
+                    let (parsed, parse_report) = measure_parse($parse, input, DAY, bench_config);
+                    let mut reports = vec![parse_report];
+                    if let Some(parsed) = parsed {
+                        $( reports.push(measure_part($func, &parsed, DAY, $part, bench_config)); )*
+                    }
+                    reports
+                },
+            };
+
+        // NOTE: unused when the solution is compiled into the main binary as a module.
+        #[allow(dead_code)]
+        fn main() {
+            use $crate::template::runner::*;
+            $crate::template::Year::set_current($crate::solution!(@year));
+            $crate::template::params::install_from_args(PARAMS);
+            let input = read_input(DAY);
+            let (parsed, parse_report) = run_parse($parse, input.as_str(), DAY);
+            let mut reports = vec![parse_report];
+            if let Some(parsed) = parsed {
+                $( reports.push(run_part($func, &parsed, DAY, $part)); )*
+            }
+            emit_reports(&reports);
+        }
+    };
+
+    (@impl $day:expr, $params:tt, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@common $day, $params);
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+        /// The current day's parts, compiled into the main binary with the `registry` feature.
+        #[allow(dead_code)]
+        pub const SOLUTION: $crate::template::registry::Solution =
+            $crate::template::registry::Solution {
+                day: DAY,
+                runner: |input, bench_config| {
+                    let _ = $crate::template::params::install(PARAMS, false, &[]);
+                    vec![$(
+                        $crate::template::runner::measure_part($func, input, DAY, $part, bench_config)
+                    ),*]
+                },
+            };
+
+        // NOTE: unused when the solution is compiled into the main binary as a module.
+        #[allow(dead_code)]
+        fn main() {
+            use $crate::template::runner::*;
+            $crate::template::Year::set_current($crate::solution!(@year));
+            $crate::template::params::install_from_args(PARAMS);
+            let input = read_input(DAY);
+            let reports = vec![$( run_part($func, &input, DAY, $part) ),*];
+            emit_reports(&reports);
+        }
+    };
+
+    (@common $day:expr, [$( [$( $name:ident { default: $default:literal $(, example: $example:literal)? $(,)? } ),* $(,)?] )?]) => {
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
+        /// The current day's parameters, read with [`param!`].
+        #[allow(dead_code)]
+        const PARAMS: &[$crate::template::params::Param] = &[$($(
+            $crate::template::params::Param {
+                name: stringify!($name),
+                default: concat!($default),
+                example: $crate::solution!(@example $($example)?),
+            },
+        )*)?];
+
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
         static ALLOC: dhat::Alloc = dhat::Alloc;
+    };
 
-        fn main() {
-            use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+    (@year) => {
+        $crate::template::Year::of_bin(option_env!("CARGO_BIN_NAME"))
+    };
+
+    (@example) => { None };
+    (@example $example:literal) => { Some(concat!($example)) };
+}
+
+/// Reads a parameter of the current day, declared with `params = [..]` in [`solution!`].
+/// The type of the value is inferred, e.g. `solve_with_connections(input, advent_of_code::param!(connections))`.
+///
+/// # Panics
+/// if the parameter is not declared or its value can not be parsed.
+#[macro_export]
+macro_rules! param {
+    ($name:ident) => {
+        $crate::template::params::get(PARAMS, stringify!($name))
+    };
+}
+
+/// Creates a test for each example of the current day, given as a table of
+/// `<test name>: <function>(<example>, <extra arguments>...) => <expected answer>`.
+///
+/// `example` reads the day's example, `example 2` its second example (`DD-2.txt`). Extra arguments are
+/// passed after the input, e.g. when an example uses a different parameter than the puzzle input.
+/// With `parse = <fn>;` in front, the input is parsed by the given function first, like in [`solution!`].
+///
+/// ```ignore
+/// advent_of_code::example_tests! {
+///     test_part_one: part_one(example) => Some(3),
+///     test_part_two: part_two(example 2) => Some(6),
+///     test_ten_connections: solve_with_connections(example, 10) => Some(40),
+/// }
+/// ```
+#[macro_export]
+macro_rules! example_tests {
+    (@number) => { None };
+    (@number $n:literal) => { Some($n) };
+
+    (parse = $parse:expr; $( $name:ident: $func:ident ( example $($n:literal)? $(, $arg:expr)* ) => $expected:expr ),* $(,)?) => {
+        #[cfg(test)]
+        mod tests {
+            use super::*;
+
+            $(
+                #[test]
+                fn $name() {
+                    $crate::template::Year::set_current($crate::solution!(@year));
+                    $crate::template::params::install(PARAMS, true, &[]).unwrap();
+                    let (path, input) = $crate::template::example_tests::read(DAY, $crate::example_tests!(@number $($n)?));
+                    let result = $func(&$parse(&input) $(, $arg)*);
+                    $crate::template::example_tests::check(&path, &result, &$expected);
+                }
+            )*
         }
     };
+    ($( $name:ident: $func:ident ( example $($n:literal)? $(, $arg:expr)* ) => $expected:expr ),* $(,)?) => {
+        $crate::example_tests!(parse = ::std::convert::identity::<&str>; $( $name: $func ( example $($n)? $(, $arg)* ) => $expected ),*);
+    };
 }
@@ -0,0 +1,194 @@
+//! Recognizes the block letters that some puzzles draw as their answer, e.g. with `#` and `.`.
+//! Both fonts used by Advent of Code are supported: the small font is 6 pixels high, the large one 10.
+
+/// Letters of the small font, 6 pixels high and (mostly) 4 pixels wide.
+const FONT_6: [(char, &str); 18] = [
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', "###\n.#.\n.#.\n.#.\n.#.\n###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+/// Letters of the large font, 10 pixels high and 6 pixels wide.
+const FONT_10: [(char, &str); 15] = [
+    (
+        'A',
+        "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'B',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####.",
+    ),
+    (
+        'C',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####.",
+    ),
+    (
+        'E',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'F',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'G',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#",
+    ),
+    (
+        'H',
+        "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'J',
+        "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###..",
+    ),
+    (
+        'K',
+        "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#",
+    ),
+    (
+        'L',
+        "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'N',
+        "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#",
+    ),
+    (
+        'P',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'R',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#",
+    ),
+    (
+        'X',
+        "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#",
+    ),
+    (
+        'Z',
+        "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######",
+    ),
+];
+
+/// Recognize the letters drawn by an ASCII-art grid. Lit pixels are `#` or `█`, anything else is unlit.
+/// Returns `None` if the grid has an unsupported height or contains a glyph that is not a letter of the font.
+pub fn recognize(art: &str) -> Option<String> {
+    let grid = parse_grid(art);
+
+    let font: &[(char, &str)] = match grid.len() {
+        6 => &FONT_6,
+        10 => &FONT_10,
+        _ => return None,
+    };
+
+    let letters: Option<String> = glyphs(&grid)
+        .iter()
+        .map(|glyph| {
+            font.iter()
+                .find(|(_, pattern)| parse_grid(pattern) == *glyph)
+                .map(|(letter, _)| *letter)
+        })
+        .collect();
+
+    letters.filter(|letters| !letters.is_empty())
+}
+
+/// Parse a grid of pixels, dropping empty rows above and below the letters.
+fn parse_grid(art: &str) -> Vec<Vec<bool>> {
+    let mut grid: Vec<Vec<bool>> = art
+        .lines()
+        .map(|line| line.chars().map(|c| c == '#' || c == '█').collect())
+        .collect();
+
+    let width = grid.iter().map(Vec::len).max().unwrap_or(0);
+    grid.iter_mut().for_each(|row| row.resize(width, false));
+
+    while grid.last().is_some_and(|row| !row.contains(&true)) {
+        grid.pop();
+    }
+    while grid.first().is_some_and(|row| !row.contains(&true)) {
+        grid.remove(0);
+    }
+
+    grid
+}
+
+/// Split a grid into glyphs at empty columns. Each glyph is trimmed to its lit columns.
+fn glyphs(grid: &[Vec<bool>]) -> Vec<Vec<Vec<bool>>> {
+    let width = grid.first().map_or(0, Vec::len);
+    let is_empty = |x: usize| grid.iter().all(|row| !row[x]);
+
+    let mut glyphs = vec![];
+    let mut start: Option<usize> = None;
+
+    for x in 0..=width {
+        match (start, x == width || is_empty(x)) {
+            (None, false) => start = Some(x),
+            (Some(from), true) => {
+                glyphs.push(grid.iter().map(|row| row[from..x].to_vec()).collect());
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    glyphs
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{FONT_6, FONT_10, recognize};
+
+    #[test]
+    fn recognizes_small_letters() {
+        let art = [
+            "#..#..###..####.",
+            "#..#...#...#....",
+            "####...#...###..",
+            "#..#...#...#....",
+            "#..#...#...#....",
+            "#..#..###..####.",
+        ]
+        .join("\n");
+        assert_eq!(recognize(&art), Some("HIE".into()));
+    }
+
+    #[test]
+    fn recognizes_block_characters() {
+        let art = "\n█  █  ██ \n█  █ █  █\n████ █  █\n█  █ █  █\n█  █ █  █\n█  █  ██ \n";
+        assert_eq!(recognize(art), Some("HO".into()));
+    }
+
+    #[test]
+    fn recognizes_all_letters() {
+        for (letter, pattern) in FONT_6.iter().chain(FONT_10.iter()) {
+            assert_eq!(recognize(pattern), Some(letter.to_string()));
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_glyphs() {
+        assert_eq!(recognize("#.#\n.#.\n#.#"), None);
+        assert_eq!(recognize(&"#.#.\n".repeat(6)), None);
+        assert_eq!(recognize(""), None);
+    }
+}
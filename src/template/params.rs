@@ -0,0 +1,320 @@
+/// Named parameters of solutions, for constants that differ between the puzzle input and the examples.
+///
+/// A day declares its parameters in [`crate::solution!`] with a default and an optional value for the examples,
+/// e.g. `params = [connections { default: 1000, example: 10 }]`, and reads them with [`crate::param!`].
+/// Values can be overridden on the command-line with `--param [DAY.]<NAME>=<VALUE>`.
+/// When several days run, overrides without a day only apply to the days that declare the parameter.
+use std::{cell::RefCell, collections::HashMap, env, fmt::Display, process, str::FromStr};
+
+use crate::template::Day;
+use crate::template::input::InputSource;
+
+/// A parameter declared by a solution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    /// Value used for all inputs other than examples.
+    pub default: &'static str,
+    /// Value used for examples, falls back to the default.
+    pub example: Option<&'static str>,
+}
+
+/// A value passed with `--param [DAY.]<NAME>=<VALUE>`.
+/// Overrides without a day apply to every day that runs. When several days run, e.g. with `cargo all`,
+/// days that do not declare the parameter ignore it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParamOverride {
+    pub day: Option<Day>,
+    pub name: String,
+    pub value: String,
+}
+
+impl FromStr for ParamOverride {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((name, value)) = s.split_once('=') else {
+            return Err(format!(
+                "invalid parameter \"{s}\", expected [DAY.]<NAME>=<VALUE>."
+            ));
+        };
+
+        let (day, name) = match name.trim().split_once('.') {
+            Some((day, name)) => (
+                Some(
+                    day.parse()
+                        .map_err(|e| format!("invalid parameter \"{s}\": {e}."))?,
+                ),
+                name.trim(),
+            ),
+            None => (None, name.trim()),
+        };
+
+        if name.is_empty() {
+            return Err(format!(
+                "invalid parameter \"{s}\", expected [DAY.]<NAME>=<VALUE>."
+            ));
+        }
+
+        Ok(ParamOverride {
+            day,
+            name: name.into(),
+            value: value.trim().into(),
+        })
+    }
+}
+
+impl Display for ParamOverride {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "{day}.")?;
+        }
+        write!(f, "{}={}", self.name, self.value)
+    }
+}
+
+/// Flag of overrides that are shared by several days. Solutions ignore them if they don't declare the parameter.
+const SHARED_FLAG: &str = "--shared-param";
+
+/// Arguments that forward the parameter overrides of `day` to its solution binary.
+/// With `shared`, overrides without a day are forwarded as shared overrides, see [`SHARED_FLAG`].
+pub fn to_args(overrides: &[ParamOverride], day: Day, shared: bool) -> Vec<String> {
+    overrides
+        .iter()
+        .filter(|x| x.day.is_none_or(|x| x == day))
+        .flat_map(|x| {
+            let flag = if shared && x.day.is_none() {
+                SHARED_FLAG
+            } else {
+                "--param"
+            };
+            [flag.into(), format!("{}={}", x.name, x.value)]
+        })
+        .collect()
+}
+
+/// Parse the overrides passed to a solution binary.
+/// Shared overrides of parameters that the solution does not declare are skipped.
+fn parse_args(params: &[Param], args: &[String]) -> Result<Vec<ParamOverride>, String> {
+    let mut overrides = vec![];
+
+    for x in args.windows(2) {
+        let is_shared = x[0] == SHARED_FLAG;
+        if x[0] != "--param" && !is_shared {
+            continue;
+        }
+
+        let x: ParamOverride = x[1].parse()?;
+        if is_shared && !params.iter().any(|param| param.name == x.name) {
+            continue;
+        }
+        overrides.push(x);
+    }
+
+    Ok(overrides)
+}
+
+// NOTE: values are kept per thread, so tests and days running on other threads can use different values.
+thread_local! {
+    static VALUES: RefCell<HashMap<&'static str, String>> = RefCell::new(HashMap::new());
+}
+
+/// Set the values of a solution's parameters for the current thread:
+/// the example value if running against an example, or else the default, unless overridden.
+/// Returns an error if an override does not belong to a declared parameter.
+pub fn install(
+    params: &'static [Param],
+    is_example: bool,
+    overrides: &[ParamOverride],
+) -> Result<(), String> {
+    let mut values: HashMap<&'static str, String> = params
+        .iter()
+        .map(|param| {
+            let value = match param.example {
+                Some(example) if is_example => example,
+                _ => param.default,
+            };
+            (param.name, value.to_string())
+        })
+        .collect();
+
+    for x in overrides {
+        let Some(param) = params.iter().find(|param| param.name == x.name) else {
+            let names: Vec<&str> = params.iter().map(|param| param.name).collect();
+            return Err(format!(
+                "unknown parameter \"{}\". Declared parameters: {}.",
+                x.name,
+                if names.is_empty() {
+                    "none".into()
+                } else {
+                    names.join(", ")
+                }
+            ));
+        };
+        values.insert(param.name, x.value.clone());
+    }
+
+    VALUES.with(|x| *x.borrow_mut() = values);
+    Ok(())
+}
+
+/// Set the values of a solution's parameters from the input and `--param` arguments of the current process.
+/// Exits if the arguments are invalid.
+pub fn install_from_args(params: &'static [Param]) {
+    let args: Vec<String> = env::args().collect();
+
+    let result = parse_args(params, &args).and_then(|overrides| {
+        let is_example = matches!(InputSource::parse(&args), InputSource::Example(_));
+        install(params, is_example, &overrides)
+    });
+
+    if let Err(e) = result {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+}
+
+/// Read the value of a parameter, see [`crate::param!`].
+/// Uses the parameter's default if no values were installed on the current thread.
+/// # Panics
+/// if the parameter is not declared or its value can not be parsed.
+pub fn get<T: FromStr>(params: &[Param], name: &str) -> T
+where
+    T::Err: Display,
+{
+    let value = VALUES.with(|x| x.borrow().get(name).cloned()).or_else(|| {
+        params
+            .iter()
+            .find(|param| param.name == name)
+            .map(|param| param.default.to_string())
+    });
+
+    let Some(value) = value else {
+        panic!("parameter \"{name}\" is not declared in `solution!`.");
+    };
+
+    value
+        .parse()
+        .unwrap_or_else(|e| panic!("invalid value \"{value}\" for parameter \"{name}\": {e}"))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Param, ParamOverride, get, install, parse_args, to_args};
+    use crate::day;
+
+    const PARAMS: &[Param] = &[
+        Param {
+            name: "connections",
+            default: "1000",
+            example: Some("10"),
+        },
+        Param {
+            name: "start",
+            default: "you",
+            example: None,
+        },
+    ];
+
+    #[test]
+    fn parses_overrides() {
+        assert_eq!(
+            "connections = 10".parse(),
+            Ok(ParamOverride {
+                day: None,
+                name: "connections".into(),
+                value: "10".into()
+            })
+        );
+        assert_eq!(
+            "8.connections=10".parse(),
+            Ok(ParamOverride {
+                day: Some(day!(8)),
+                name: "connections".into(),
+                value: "10".into()
+            })
+        );
+        assert!("connections".parse::<ParamOverride>().is_err());
+        assert!("=10".parse::<ParamOverride>().is_err());
+        assert!("x.connections=10".parse::<ParamOverride>().is_err());
+    }
+
+    #[test]
+    fn forwards_overrides_of_a_day() {
+        let overrides: Vec<ParamOverride> = ["8.connections=10", "start=you", "11.start=svr"]
+            .iter()
+            .map(|x| x.parse().unwrap())
+            .collect();
+
+        assert_eq!(
+            to_args(&overrides, day!(8), false),
+            ["--param", "connections=10", "--param", "start=you"]
+        );
+        assert_eq!(
+            to_args(&overrides, day!(8), true),
+            ["--param", "connections=10", "--shared-param", "start=you"]
+        );
+    }
+
+    #[test]
+    fn skips_undeclared_shared_overrides() {
+        let args: Vec<String> = [
+            "08",
+            "--shared-param",
+            "typo=5",
+            "--shared-param",
+            "connections=5",
+        ]
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+        assert_eq!(
+            parse_args(PARAMS, &args),
+            Ok(vec!["connections=5".parse().unwrap()])
+        );
+
+        let args: Vec<String> = ["08", "--param", "typo=5"]
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        let overrides = parse_args(PARAMS, &args).unwrap();
+        assert!(install(PARAMS, false, &overrides).is_err());
+    }
+
+    #[test]
+    fn uses_defaults() {
+        assert_eq!(get::<usize>(PARAMS, "connections"), 1000);
+
+        install(PARAMS, false, &[]).unwrap();
+        assert_eq!(get::<usize>(PARAMS, "connections"), 1000);
+        assert_eq!(get::<String>(PARAMS, "start"), "you");
+    }
+
+    #[test]
+    fn uses_example_values() {
+        install(PARAMS, true, &[]).unwrap();
+        assert_eq!(get::<usize>(PARAMS, "connections"), 10);
+        assert_eq!(get::<String>(PARAMS, "start"), "you");
+    }
+
+    #[test]
+    fn applies_overrides() {
+        let overrides = ["connections=5".parse().unwrap()];
+        install(PARAMS, true, &overrides).unwrap();
+        assert_eq!(get::<usize>(PARAMS, "connections"), 5);
+
+        let overrides = ["typo=5".parse().unwrap()];
+        assert_eq!(
+            install(PARAMS, false, &overrides),
+            Err("unknown parameter \"typo\". Declared parameters: connections, start.".into())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid value \"you\" for parameter \"start\"")]
+    fn rejects_invalid_values() {
+        get::<u32>(PARAMS, "start");
+    }
+}
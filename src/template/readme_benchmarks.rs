@@ -2,8 +2,9 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
-use crate::template::Day;
+use crate::template::heap::format_bytes;
 use crate::template::timings::Timings;
+use crate::template::{Day, Year};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -27,7 +28,7 @@ pub struct TablePosition {
 
 #[must_use]
 pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+    Year::current().bin_path(day)
 }
 
 fn locate_table(readme: &str) -> Result<TablePosition, Error> {
@@ -55,23 +56,42 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
 fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
     let header = format!("{prefix} Benchmarks");
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
+    // NOTE: allocations are only shown if they were recorded for at least one day.
+    let has_heap = timings.data.iter().any(|timing| timing.heap().is_some());
+
+    let mut lines: Vec<String> = vec![MARKER.into(), header, String::new()];
+
+    if has_heap {
+        lines.push("| Day | Parse | Part 1 | Part 2 | Allocated | Peak |".into());
+        lines.push("| :---: | :---: | :---: | :---: | :---: | :---:  |".into());
+    } else {
+        lines.push("| Day | Parse | Part 1 | Part 2 |".into());
+        lines.push("| :---: | :---: | :---: | :---:  |".into());
+    }
 
     for timing in timings.data {
         let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
+        let heap = timing.heap();
+
+        let mut line = format!(
+            "| [Day {}]({}) | `{}` | `{}` | `{}` |",
             timing.day.into_inner(),
             path,
+            timing.parse.unwrap_or_else(|| "-".into()),
             timing.part_1.unwrap_or_else(|| "-".into()),
             timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+        );
+
+        if has_heap {
+            let format = |bytes: Option<u64>| bytes.map_or_else(|| "-".into(), format_bytes);
+            line.push_str(&format!(
+                " `{}` | `{}` |",
+                format(heap.map(|x| x.total_bytes)),
+                format(heap.map(|x| x.peak_bytes))
+            ));
+        }
+
+        lines.push(line);
     }
 
     lines.push(String::new());
@@ -100,29 +120,22 @@ pub fn update(timings: Timings) -> Result<(), Error> {
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{MARKER, update_content};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use crate::{
+        day,
+        template::heap::HeapStats,
+        template::test_support::timing,
+        template::timings::{Timing, Timings},
+    };
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
+                timing(day!(1), Some("10ms"), Some("20ms"), 3e+10),
                 Timing {
-                    day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
-                    total_nanos: 3e+10,
-                },
-                Timing {
-                    day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
-                    total_nanos: 7e+10,
-                },
-                Timing {
-                    day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: Some("50ms".into()),
-                    total_nanos: 9e+10,
+                    parse: Some("5ms".into()),
+                    ..timing(day!(2), Some("30ms"), Some("40ms"), 7e+10)
                 },
+                timing(day!(4), Some("40ms"), Some("50ms"), 9e+10),
             ],
         }
     }
@@ -167,11 +180,11 @@ mod tests {
             "<!--- benchmarking table --->",
             "## Benchmarks",
             "",
-            "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| Day | Parse | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/01.rs) | `-` | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/02.rs) | `5ms` | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/04.rs) | `-` | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -180,4 +193,36 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_benchmarks_with_allocations() {
+        let mut timings = get_mock_timings();
+        timings.data[1].part_1_heap = Some(HeapStats {
+            total_bytes: 2048,
+            total_blocks: 2,
+            peak_bytes: 1024,
+        });
+        timings.data[1].part_2_heap = Some(HeapStats {
+            total_bytes: 512,
+            total_blocks: 1,
+            peak_bytes: 512,
+        });
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0).unwrap();
+
+        let lines: Vec<&str> = s.lines().collect();
+        assert_eq!(
+            lines[3],
+            "| Day | Parse | Part 1 | Part 2 | Allocated | Peak |"
+        );
+        assert_eq!(
+            lines[5],
+            "| [Day 1](./src/bin/01.rs) | `-` | `10ms` | `20ms` | `-` | `-` |"
+        );
+        assert_eq!(
+            lines[6],
+            "| [Day 2](./src/bin/02.rs) | `5ms` | `30ms` | `40ms` | `2.5 KiB` | `1.0 KiB` |"
+        );
+    }
 }
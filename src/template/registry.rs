@@ -0,0 +1,50 @@
+/// In-process solution registry, enabled with the `registry` feature.
+///
+/// With the feature enabled, a build script compiles every solution in `src/bin/` into the main
+/// binary as a module. The `solution!` macro exposes a [`Solution`] for each day, which the main
+/// binary installs here on startup so `cargo all` and `cargo time` can call the solutions directly
+/// instead of spawning `cargo run` for each day.
+use std::sync::OnceLock;
+
+use crate::template::{Day, bench::BenchConfig, input::InputSource, report::PartReport};
+
+/// Runs all parts of a solution, including its parse step, against an input and returns their reports.
+/// The parts are benched if a [`BenchConfig`] is passed.
+pub type SolutionRunner = fn(&str, Option<&BenchConfig>) -> Vec<PartReport>;
+
+/// The parts of a single day's solution.
+pub struct Solution {
+    pub day: Day,
+    pub runner: SolutionRunner,
+}
+
+impl Solution {
+    /// Run all parts of the solution against an input of the day.
+    /// Returns no reports if the input could not be read.
+    pub fn run(&self, source: &InputSource, bench_config: Option<&BenchConfig>) -> Vec<PartReport> {
+        match source.read(self.day) {
+            Ok(input) => (self.runner)(&input, bench_config),
+            Err(e) => {
+                eprintln!("Failed to read input: {e}");
+                vec![]
+            }
+        }
+    }
+}
+
+static SOLUTIONS: OnceLock<&'static [Solution]> = OnceLock::new();
+
+/// Install the solutions compiled into the current binary. Only the first call has an effect.
+pub fn install(solutions: &'static [Solution]) {
+    let _ = SOLUTIONS.set(solutions);
+}
+
+/// Returns the installed solutions, or `None` if the registry is not in use.
+pub fn solutions() -> Option<&'static [Solution]> {
+    SOLUTIONS.get().copied()
+}
+
+/// Returns the installed solution for a day, if any.
+pub fn get(day: Day) -> Option<&'static Solution> {
+    solutions()?.iter().find(|solution| solution.day == day)
+}
@@ -0,0 +1,399 @@
+/// Machine-readable reports emitted by solution binaries.
+use std::{collections::HashMap, env, process, str::FromStr, time::Duration};
+use tinyjson::JsonValue;
+
+use crate::template::{
+    Day,
+    bench::{BenchStats, stats_from_json, stats_to_json},
+    heap::{HeapStats, heap_from_json, heap_to_json},
+};
+
+/// Output format of a solution binary, selected via `--format <human|json|ndjson>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable text (default).
+    Human,
+    /// A single JSON array containing all part reports, printed once all parts ran.
+    Json,
+    /// One JSON object per part, printed as soon as the part finished.
+    Ndjson,
+}
+
+impl OutputFormat {
+    /// Reads the output format from the command-line arguments of the current process.
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+
+        let Some(index) = args.iter().position(|x| x == "--format") else {
+            return OutputFormat::Human;
+        };
+
+        match args.get(index + 1).map(|x| x.parse()) {
+            Some(Ok(format)) => format,
+            _ => {
+                eprintln!("Unexpected command-line input. Format: --format <human|json|ndjson>");
+                process::exit(1);
+            }
+        }
+    }
+
+    pub fn is_human(self) -> bool {
+        self == OutputFormat::Human
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(OutputFormat::Human),
+            "json" => Ok(OutputFormat::Json),
+            "ndjson" => Ok(OutputFormat::Ndjson),
+            x => Err(format!("unknown output format `{x}`.")),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Outcome of running a single part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartStatus {
+    /// The part returned an answer.
+    Solved,
+    /// The part returned `None`, i.e. it is not implemented yet.
+    Unsolved,
+    /// The part returned an error.
+    Failed,
+    /// The part panicked.
+    Panicked,
+    /// The part was killed because it exceeded its timeout.
+    Timeout,
+    /// The part was killed because it exceeded its memory limit.
+    Oom,
+    /// The part started running. Emitted before each part in the `ndjson` format, so that a
+    /// parent process knows which part a solution is running.
+    Running,
+}
+
+impl PartStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PartStatus::Solved => "solved",
+            PartStatus::Unsolved => "unsolved",
+            PartStatus::Failed => "failed",
+            PartStatus::Panicked => "panicked",
+            PartStatus::Timeout => "timeout",
+            PartStatus::Oom => "oom",
+            PartStatus::Running => "running",
+        }
+    }
+
+    /// Whether the part returned an error, panicked or was killed.
+    pub fn is_failure(self) -> bool {
+        matches!(
+            self,
+            PartStatus::Failed | PartStatus::Panicked | PartStatus::Timeout | PartStatus::Oom
+        )
+    }
+}
+
+impl FromStr for PartStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "solved" => Ok(PartStatus::Solved),
+            "unsolved" => Ok(PartStatus::Unsolved),
+            "failed" => Ok(PartStatus::Failed),
+            "panicked" => Ok(PartStatus::Panicked),
+            "timeout" => Ok(PartStatus::Timeout),
+            "oom" => Ok(PartStatus::Oom),
+            "running" => Ok(PartStatus::Running),
+            x => Err(format!("unknown part status `{x}`.")),
+        }
+    }
+}
+
+/// Part number of the report of a solution's shared parse step, see [`PartReport::is_parse`].
+pub const PARSE_PART: u8 = 0;
+
+/// Result of running a single part of a solution.
+/// Can be serialized from / to JSON.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartReport {
+    pub day: Day,
+    pub part: u8,
+    pub answer: Option<String>,
+    /// Execution time in nanoseconds. When benched, this is the average of all samples.
+    pub nanos: u128,
+    pub samples: u128,
+    pub status: PartStatus,
+    /// Benchmark statistics, present if the part was benched.
+    pub stats: Option<BenchStats>,
+    /// The error message of a failed part, or the panic message of a panicked part.
+    pub error: Option<String>,
+    /// Allocations of the part, present if the solution was built with the `dhat-heap` feature.
+    pub heap: Option<HeapStats>,
+}
+
+impl PartReport {
+    /// A report for a part that has no answer, e.g. because it is still running or was killed.
+    pub fn without_answer(day: Day, part: u8, status: PartStatus, error: Option<String>) -> Self {
+        PartReport {
+            day,
+            part,
+            answer: None,
+            nanos: 0,
+            samples: 1,
+            status,
+            stats: None,
+            error,
+            heap: None,
+        }
+    }
+
+    /// Whether this report measures the shared parse step instead of a part.
+    pub fn is_parse(&self) -> bool {
+        self.part == PARSE_PART
+    }
+
+    /// Name of the reported step as printed by the runner, e.g. "Part 1" or "Parse".
+    pub fn label(&self) -> String {
+        if self.is_parse() {
+            "Parse".into()
+        } else {
+            format!("Part {}", self.part)
+        }
+    }
+
+    pub fn duration(&self) -> Duration {
+        #[allow(clippy::cast_possible_truncation)]
+        Duration::from_nanos(self.nanos as u64)
+    }
+
+    /// Serialize the report to a single line of JSON.
+    pub fn to_json_line(&self) -> String {
+        JsonValue::from(self)
+            .stringify()
+            .expect("part reports only contain serializable values")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartReport> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &PartReport) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert(
+            "answer".into(),
+            match &value.answer {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert("nanos".into(), JsonValue::Number(value.nanos as f64));
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert(
+            "status".into(),
+            JsonValue::String(value.status.as_str().into()),
+        );
+        map.insert("stats".into(), stats_to_json(value.stats.as_ref()));
+
+        if let Some(error) = &value.error {
+            map.insert("error".into(), JsonValue::String(error.clone()));
+        }
+
+        if value.heap.is_some() {
+            map.insert("heap".into(), heap_to_json(value.heap.as_ref()));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartReport {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected report to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected report.day to be a Day struct.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|v| *v as u8)
+            .ok_or("Expected report.part to be a number.")?;
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected report.answer to be null or string.")?;
+
+        let nanos = json
+            .get("nanos")
+            .and_then(|v| v.get::<f64>())
+            .map(|v| *v as u128)
+            .ok_or("Expected report.nanos to be a number.")?;
+
+        let samples = json
+            .get("samples")
+            .and_then(|v| v.get::<f64>())
+            .map(|v| *v as u128)
+            .ok_or("Expected report.samples to be a number.")?;
+
+        let status = json
+            .get("status")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected report.status to be a string.")?
+            .parse()?;
+
+        let stats = stats_from_json(json, "stats")?;
+
+        let error = json.get("error").and_then(|v| v.get::<String>()).cloned();
+
+        let heap = heap_from_json(json, "heap")?;
+
+        Ok(PartReport {
+            day,
+            part,
+            answer: answer.cloned(),
+            nanos,
+            samples,
+            status,
+            stats,
+            error,
+            heap,
+        })
+    }
+}
+
+impl FromStr for PartReport {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = JsonValue::from_str(s).or(Err("not valid JSON."))?;
+        PartReport::try_from(&json)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{OutputFormat, PartReport, PartStatus};
+    use crate::{
+        day,
+        template::{bench::BenchStats, heap::HeapStats},
+    };
+
+    fn get_mock_report(answer: Option<&str>) -> PartReport {
+        PartReport {
+            day: day!(1),
+            part: 2,
+            answer: answer.map(Into::into),
+            nanos: 74_130_074,
+            samples: 100,
+            status: if answer.is_some() {
+                PartStatus::Solved
+            } else {
+                PartStatus::Unsolved
+            },
+            stats: None,
+            error: None,
+            heap: None,
+        }
+    }
+
+    #[test]
+    fn roundtrips_reports() {
+        let report = get_mock_report(Some("42"));
+        let parsed: PartReport = report.to_json_line().parse().unwrap();
+        assert_eq!(parsed, report);
+    }
+
+    #[test]
+    fn roundtrips_errors() {
+        let mut report = get_mock_report(None);
+        report.status = PartStatus::Failed;
+        report.error = Some("invalid digit found in string".into());
+        let parsed: PartReport = report.to_json_line().parse().unwrap();
+        assert_eq!(parsed, report);
+    }
+
+    #[test]
+    fn roundtrips_heap_stats() {
+        let mut report = get_mock_report(Some("42"));
+        report.heap = Some(HeapStats {
+            total_bytes: 276,
+            total_blocks: 3,
+            peak_bytes: 232,
+        });
+        let parsed: PartReport = report.to_json_line().parse().unwrap();
+        assert_eq!(parsed, report);
+    }
+
+    #[test]
+    fn roundtrips_stats() {
+        let mut report = get_mock_report(Some("42"));
+        report.stats =
+            BenchStats::from_samples(&[Duration::from_nanos(10), Duration::from_nanos(30)]);
+        let parsed: PartReport = report.to_json_line().parse().unwrap();
+        assert_eq!(parsed.stats, report.stats);
+    }
+
+    #[test]
+    fn roundtrips_answers_with_patterns() {
+        let report = get_mock_report(Some("@ @ @ ( ) ms (2s @ 5 samples)\nfoo"));
+        let parsed: PartReport = report.to_json_line().parse().unwrap();
+        assert_eq!(parsed.answer, report.answer);
+        assert_eq!(parsed.nanos, 74_130_074);
+    }
+
+    #[test]
+    fn roundtrips_unsolved_parts() {
+        let report = get_mock_report(None);
+        let parsed: PartReport = report.to_json_line().parse().unwrap();
+        assert_eq!(parsed.answer, None);
+        assert_eq!(parsed.status, PartStatus::Unsolved);
+    }
+
+    #[test]
+    fn serializes_to_single_line() {
+        let report = get_mock_report(Some("a\nb"));
+        assert_eq!(report.to_json_line().lines().count(), 1);
+    }
+
+    #[test]
+    fn rejects_human_output() {
+        assert!(
+            "Part 1: 0 (74.13ns @ 100000 samples)"
+                .parse::<PartReport>()
+                .is_err()
+        );
+        assert!(r#"{ "day": "01" }"#.parse::<PartReport>().is_err());
+    }
+
+    #[test]
+    fn parses_output_formats() {
+        assert_eq!("json".parse::<OutputFormat>(), Ok(OutputFormat::Json));
+        assert_eq!("ndjson".parse::<OutputFormat>(), Ok(OutputFormat::Ndjson));
+        assert_eq!("human".parse::<OutputFormat>(), Ok(OutputFormat::Human));
+        assert!("yaml".parse::<OutputFormat>().is_err());
+    }
+}
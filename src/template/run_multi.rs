@@ -1,38 +1,159 @@
-use std::{collections::HashSet, io};
+use std::{
+    collections::{BTreeMap, HashSet},
+    io, process,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
 
-use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
+use crate::template::{
+    ANSI_BOLD, ANSI_ITALIC, ANSI_RED, ANSI_RESET, Day, Year, answers,
+    bench::BenchConfig,
+    input::InputSource,
+    limits::Limits,
+    params::ParamOverride,
+    registry,
+    report::PartReport,
+    runner,
+    team::{self, MemberReports},
+};
 
 use super::{
     all_days,
     timings::{Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+/// Output of a single day's solution.
+#[derive(Debug, Default)]
+pub struct DayOutput {
+    pub reports: Vec<PartReport>,
+    /// Reports per team member, if the day's inputs belong to team members. See [`team`].
+    pub members: Vec<MemberReports>,
+    /// Lines printed by the solution itself, if they were not forwarded while running.
+    pub stdout: Vec<String>,
+    pub stderr: Vec<String>,
+}
 
-    let mut need_space = false;
+/// How solutions are built and run.
+#[derive(Debug, Clone, Copy)]
+pub struct RunOptions<'a> {
+    pub is_release: bool,
+    /// Build solutions with the `dhat-heap` feature, so they report their allocations.
+    pub is_dhat: bool,
+    pub is_timed: bool,
+    /// Forwarded to the solutions to configure the benchmark engine when timed.
+    pub bench_args: &'a [String],
+    /// Parts are killed when they exceed these limits.
+    pub limits: &'a Limits,
+    /// Forwarded to the solutions to override their parameters.
+    pub params: &'a [ParamOverride],
+    /// Whether several days run, so overrides without a day only apply to the days that declare them.
+    pub shares_params: bool,
+}
+
+/// Run the solutions of `days_to_run` in day order.
+/// When not timed, up to `jobs` days run concurrently. Their output is buffered and printed in day order.
+/// Days whose inputs belong to team members print a matrix of answers per member instead.
+pub fn run_multi(days_to_run: &HashSet<Day>, options: &RunOptions, jobs: usize) -> Option<Timings> {
+    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+    let mut failures: Vec<PartReport> = vec![];
+    let (is_timed, limits) = (options.is_timed, options.limits);
 
     // NOTE: use non-duplicate, sorted day values.
-    all_days()
-        .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
-            }
-            need_space = true;
+    let days: Vec<Day> = all_days().filter(|day| days_to_run.contains(day)).collect();
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+    let run_day = |day: Day, forward_output: bool| run_day(day, options, forward_output);
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+    let mut print_day = |index: usize, day: Day, output: Option<DayOutput>| {
+        if index > 0 {
+            println!();
+        }
+
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
+
+        // NOTE: output is only passed here when it was buffered, otherwise the day runs now.
+        let output = output.unwrap_or_else(|| run_day(day, true));
+
+        output.stdout.iter().for_each(|line| println!("{line}"));
+        output.stderr.iter().for_each(|line| eprintln!("{line}"));
+
+        if !output.members.is_empty() {
+            team::print_matrix(&output.members);
+            failures.extend(team::failures(&output.members));
+        } else if output.reports.is_empty() {
+            println!("Not solved.");
+        } else {
+            for report in &output.reports {
+                if report.status.is_failure() {
+                    runner::print_failure(report);
+                    failures.push(report.clone());
+                    continue;
+                }
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
+                if report.is_parse() {
+                    runner::print_parse(&runner::format_duration(
+                        &report.duration(),
+                        report.samples,
+                    ));
+                    runner::print_stats(report.stats.as_ref());
+                    runner::print_heap(report.heap.as_ref());
+                    continue;
+                }
+
+                runner::print_result(
+                    &report.answer,
+                    &report.label(),
+                    &runner::format_duration(&report.duration(), report.samples),
+                );
+                runner::print_stats(report.stats.as_ref());
+                runner::print_heap(report.heap.as_ref());
+                runner::print_verdict(&answers::check(report));
             }
-        });
+            timings.push(child_commands::timing_from_reports(&output.reports, day));
+        }
+    };
+
+    if registry::solutions().is_some() && !limits.is_empty() {
+        eprintln!("Timeouts and memory limits are ignored when running solutions in-process.");
+    }
+
+    if registry::solutions().is_some() && options.is_dhat {
+        eprintln!("Allocations are not recorded when running solutions in-process.");
+    }
+
+    if registry::solutions().is_some() && !options.params.is_empty() {
+        eprintln!("Parameter overrides are ignored when running solutions in-process.");
+    }
+
+    // NOTE: benchmarks always run serially to avoid interference between days.
+    if jobs > 1 && !is_timed {
+        if registry::solutions().is_none()
+            && !child_commands::build_solutions(options.is_release, options.is_dhat)
+        {
+            eprintln!("Failed to build solutions.");
+            process::exit(1);
+        }
+
+        run_parallel(
+            &days,
+            jobs,
+            |day| run_day(day, false),
+            |index, output| {
+                print_day(index, days[index], Some(output));
+            },
+        );
+    } else {
+        for (index, day) in days.iter().enumerate() {
+            print_day(index, *day, None);
+        }
+    }
+
+    if !failures.is_empty() {
+        print_failures(&failures);
+    }
 
     if is_timed {
         let timings = Timings { data: timings };
@@ -46,6 +167,124 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
     }
 }
 
+/// Print a summary of all parts that failed or panicked.
+fn print_failures(failures: &[PartReport]) {
+    println!(
+        "\n{ANSI_BOLD}{} {} failed:{ANSI_RESET}",
+        failures.len(),
+        if failures.len() == 1 { "part" } else { "parts" }
+    );
+
+    for report in failures {
+        let error = report.error.as_deref().unwrap_or_default();
+        println!(
+            "  {ANSI_RED}✖{ANSI_RESET} Day {}, {}: {}",
+            report.day,
+            report.label().to_lowercase(),
+            error.lines().next().unwrap_or_default()
+        );
+    }
+}
+
+/// Run the solution of a single day, either in-process via the registry or as a child command.
+/// If the day's inputs belong to team members, the solution runs against the input of every member.
+/// Benchmarks only run against the input of the first member, so that they stay comparable.
+pub fn run_day(day: Day, options: &RunOptions, forward_output: bool) -> DayOutput {
+    let members = team::members(day);
+
+    if members.is_empty() {
+        return run_input(day, options, &InputSource::Puzzle, forward_output);
+    }
+
+    if options.is_timed {
+        let source = InputSource::Member(members[0].clone());
+        return run_input(day, options, &source, forward_output);
+    }
+
+    let mut output = DayOutput::default();
+
+    for member in members {
+        // NOTE: output is buffered, so it is not interleaved with the matrix of answers.
+        let member_output = run_input(day, options, &InputSource::Member(member.clone()), false);
+        output.stdout.extend(member_output.stdout);
+        output.stderr.extend(member_output.stderr);
+        output.members.push(MemberReports {
+            member,
+            reports: member_output.reports,
+        });
+    }
+
+    output
+}
+
+/// Run the solution of a single day against an input.
+fn run_input(
+    day: Day,
+    options: &RunOptions,
+    source: &InputSource,
+    forward_output: bool,
+) -> DayOutput {
+    if registry::solutions().is_some() {
+        // the registry only contains days that have been scaffolded.
+        let bench_config = options
+            .is_timed
+            .then(|| BenchConfig::parse(options.bench_args));
+        DayOutput {
+            reports: registry::get(day)
+                .map(|solution| solution.run(source, bench_config.as_ref()))
+                .unwrap_or_default(),
+            ..DayOutput::default()
+        }
+    } else {
+        child_commands::run_solution(day, options, source, forward_output).unwrap()
+    }
+}
+
+/// Run `run_day` for all days on up to `jobs` threads.
+/// `on_output` is called on the current thread in day order as soon as a day and all days before it finished.
+fn run_parallel(
+    days: &[Day],
+    jobs: usize,
+    run_day: impl Fn(Day) -> DayOutput + Sync,
+    mut on_output: impl FnMut(usize, DayOutput),
+) {
+    let next_index = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(days.len()) {
+            let sender = sender.clone();
+            let (next_index, run_day) = (&next_index, &run_day);
+
+            scope.spawn(move || {
+                loop {
+                    let index = next_index.fetch_add(1, Ordering::Relaxed);
+                    let Some(day) = days.get(index) else {
+                        break;
+                    };
+                    if sender.send((index, run_day(*day))).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+
+        drop(sender);
+
+        let mut pending = BTreeMap::new();
+        let mut next_to_print = 0;
+
+        for (index, output) in receiver {
+            pending.insert(index, output);
+
+            while let Some(output) = pending.remove(&next_to_print) {
+                on_output(next_to_print, output);
+                next_to_print += 1;
+            }
+        }
+    });
+}
+
 #[allow(dead_code)]
 #[derive(Debug)]
 pub enum Error {
@@ -61,43 +300,91 @@ impl From<std::io::Error> for Error {
 
 #[must_use]
 pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+    Year::current().bin_path(day)
 }
 
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// This module encapsulates interaction with these binaries, both invoking them as well as collecting their reports.
 pub mod child_commands {
-    use super::{Error, get_path_for_bin};
-    use crate::template::Day;
+    use super::{DayOutput, Error, RunOptions, get_path_for_bin};
+    use crate::template::{
+        Day, Year,
+        input::InputSource,
+        params,
+        report::{PARSE_PART, PartReport, PartStatus},
+    };
     use std::{
         io::{BufRead, BufReader},
         path::Path,
-        process::{Command, Stdio},
+        process::{Command, ExitStatus, Stdio},
+        sync::mpsc::{self, RecvTimeoutError},
         thread,
+        time::Instant,
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    /// Build all solution bins, so that they can be run concurrently without waiting on cargo's build lock.
+    pub fn build_solutions(is_release: bool, is_dhat: bool) -> bool {
+        let mut args = vec!["build", "--quiet", "--bins"];
+        args.extend(profile_args(is_release, is_dhat));
+
+        Command::new("cargo")
+            .args(&args)
+            .status()
+            .is_ok_and(|status| status.success())
+    }
+
+    /// Cargo arguments that select the build profile. DHAT builds use the `dhat` profile, which inherits from `release`.
+    fn profile_args(is_release: bool, is_dhat: bool) -> Vec<&'static str> {
+        if is_dhat {
+            vec!["--profile", "dhat", "--features", "dhat-heap"]
+        } else if is_release {
+            vec!["--release"]
+        } else {
+            vec![]
+        }
+    }
+
+    /// Run the solution bin for a given day against an input and collect the reports of its parts.
+    /// Any other output is forwarded while the solution runs if `forward_output` is set, and buffered otherwise.
+    /// Parts that exceed their timeout are killed, parts that exceed their memory limit abort.
+    /// Both are reported with a corresponding status.
+    pub fn run_solution(
+        day: Day,
+        options: &RunOptions,
+        source: &InputSource,
+        forward_output: bool,
+    ) -> Result<DayOutput, Error> {
+        let limits = options.limits;
+
         // skip command invocation for days that have not been scaffolded yet.
         if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+            return Ok(DayOutput::default());
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        let bin_name = Year::current().bin_name(day);
+        let mut args = vec!["run", "--quiet", "--bin", &bin_name];
+        args.extend(profile_args(options.is_release, options.is_dhat));
 
-        if is_release {
-            args.push("--release");
-        }
+        // request machine-readable reports from the child.
+        args.extend(["--", "--format", "ndjson"]);
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
+        if options.is_timed {
+            // mirror `--time` flag and benchmark configuration to child invocations.
             args.push("--time");
+            args.extend(options.bench_args.iter().map(String::as_str));
         }
 
+        let input_args = source.to_args();
+        args.extend(input_args.iter().map(String::as_str));
+
+        let limit_args = limits.to_args();
+        args.extend(limit_args.iter().map(String::as_str));
+
+        let param_args = params::to_args(options.params, day, options.shares_params);
+        args.extend(param_args.iter().map(String::as_str));
+
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // forward or buffer output while collecting reports from stdout lines.
 
         let mut cmd = Command::new("cargo")
             .args(&args)
@@ -108,87 +395,171 @@ pub mod child_commands {
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        let mut output = vec![];
+        let mut output = DayOutput::default();
 
         let thread = thread::spawn(move || {
+            let mut buffer = vec![];
             stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
+                let line = line.unwrap();
+                if forward_output {
+                    eprintln!("{line}");
+                }
+                buffer.push(line);
             });
+            buffer
+        });
+
+        // read stdout on a separate thread, so the running part can be killed when it exceeds its timeout.
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for line in stdout.lines() {
+                if sender.send(line.unwrap()).is_err() {
+                    break;
+                }
+            }
         });
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+        // the part that is currently running and when it started.
+        let mut running: Option<(u8, Instant)> = None;
+
+        loop {
+            let deadline = running
+                .and_then(|(part, started)| Some((part, started + limits.timeout(day, part)?)));
+
+            let line = match deadline {
+                Some((part, deadline)) => {
+                    match receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+                    {
+                        Ok(line) => line,
+                        Err(RecvTimeoutError::Timeout) => {
+                            let _ = cmd.kill();
+                            let timeout = limits.timeout(day, part).unwrap_or_default();
+                            output.reports.push(PartReport::without_answer(
+                                day,
+                                part,
+                                PartStatus::Timeout,
+                                Some(format!("timed out after {timeout:?}")),
+                            ));
+                            running = None;
+                            continue;
+                        }
+                        Err(RecvTimeoutError::Disconnected) => break,
+                    }
+                }
+                None => match receiver.recv() {
+                    Ok(line) => line,
+                    Err(_) => break,
+                },
+            };
+
+            match line.parse::<PartReport>() {
+                Ok(report) if report.status == PartStatus::Running => {
+                    running = Some((report.part, Instant::now()));
+                }
+                Ok(report) => {
+                    running = None;
+                    output.reports.push(report);
+                }
+                // anything that is not a report was printed by the solution itself.
+                Err(_) if forward_output => println!("{line}"),
+                Err(_) => output.stdout.push(line),
+            }
+        }
+
+        let stderr = thread.join().unwrap();
+        let status = cmd.wait()?;
+
+        // a part that did not report back aborted. Allocations beyond the memory limit abort the process.
+        if let Some((part, _)) = running
+            && let Some(limit) = limits.memory_limit(day, part)
+            && is_abort(status)
+        {
+            output.reports.push(PartReport::without_answer(
+                day,
+                part,
+                PartStatus::Oom,
+                Some(format!(
+                    "exceeded the memory limit of {} MB",
+                    limit / 1024 / 1024
+                )),
+            ));
         }
 
-        thread.join().unwrap();
-        cmd.wait()?;
+        if !forward_output {
+            output.stderr = stderr;
+        }
 
         Ok(output)
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
+    /// Whether a process was aborted by `SIGABRT`, which is what a failed allocation does.
+    #[cfg(unix)]
+    fn is_abort(status: ExitStatus) -> bool {
+        use std::os::unix::process::ExitStatusExt;
+        status.signal() == Some(libc::SIGABRT)
+    }
+
+    /// Memory limits are only supported on unix, so no process aborts because of them.
+    #[cfg(not(unix))]
+    fn is_abort(_status: ExitStatus) -> bool {
+        false
+    }
+
+    /// Collect the execution times reported by a solution into a [`super::Timing`].
+    pub fn timing_from_reports(reports: &[PartReport], day: Day) -> super::Timing {
         let mut timings = super::Timing {
             day,
+            parse: None,
             part_1: None,
             part_2: None,
             total_nanos: 0_f64,
+            part_1_stats: None,
+            part_2_stats: None,
+            parse_stats: None,
+            parse_heap: None,
+            part_1_heap: None,
+            part_2_heap: None,
         };
 
-        output
+        reports
             .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
-
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
+            .filter(|report| report.status == PartStatus::Solved || report.status.is_failure())
+            .for_each(|report| {
+                // failed parts are recorded by their status instead of a time.
+                let timing_str = if report.status == PartStatus::Solved {
+                    format!("{:.1?}", report.duration())
+                } else {
+                    report.status.as_str().to_string()
                 };
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
+                match report.part {
+                    PARSE_PART => {
+                        timings.parse = Some(timing_str);
+                        timings.parse_stats = report.stats;
+                        timings.parse_heap = report.heap;
+                    }
+                    1 => {
+                        timings.part_1 = Some(timing_str);
+                        timings.part_1_stats = report.stats;
+                        timings.part_1_heap = report.heap;
+                    }
+                    2 => {
+                        timings.part_2 = Some(timing_str);
+                        timings.part_2_stats = report.stats;
+                        timings.part_2_heap = report.heap;
+                    }
+                    _ => {}
                 }
 
-                timings.total_nanos += nanos;
+                #[allow(clippy::cast_precision_loss)]
+                {
+                    timings.total_nanos += report.nanos as f64;
+                }
             });
 
         timings
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
-    }
-
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .next_back()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
-    }
-
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
     #[cfg(feature = "test_lib")]
     macro_rules! assert_approx_eq {
@@ -205,47 +576,55 @@ pub mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use super::timing_from_reports;
 
-        use crate::day;
+        use crate::{
+            day,
+            template::{
+                report::{PARSE_PART, PartStatus},
+                test_support::report,
+            },
+        };
 
         #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
+        fn collects_execution_times() {
+            let res = timing_from_reports(
                 &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
+                    report(1, Some("0"), PartStatus::Solved, 74),
+                    report(2, Some("10"), PartStatus::Solved, 74_130_000),
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_approx_eq!(res.total_nanos, 74130074_f64);
+            assert_eq!(res.part_1.unwrap(), "74.0ns");
+            assert_eq!(res.part_2.unwrap(), "74.1ms");
         }
 
         #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
+        fn collects_with_patterns_in_answers() {
+            let res = timing_from_reports(
                 &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
+                    report(
+                        1,
+                        Some("@ @ @ ( ) ms (2s @ 5 samples)"),
+                        PartStatus::Solved,
+                        2_000_000_000,
+                    ),
+                    report(2, Some("10s"), PartStatus::Solved, 100_000_000),
                 ],
                 day!(1),
             );
             assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_eq!(res.part_1.unwrap(), "2.0s");
+            assert_eq!(res.part_2.unwrap(), "100.0ms");
         }
 
         #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
+        fn collects_missing_parts() {
+            let res = timing_from_reports(
                 &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
+                    report(1, None, PartStatus::Unsolved, 10),
+                    report(2, None, PartStatus::Unsolved, 10),
                 ],
                 day!(1),
             );
@@ -253,5 +632,34 @@ pub mod child_commands {
             assert_eq!(res.part_1.is_none(), true);
             assert_eq!(res.part_2.is_none(), true);
         }
+
+        #[test]
+        fn collects_killed_parts() {
+            let res = timing_from_reports(
+                &[
+                    report(1, Some("1"), PartStatus::Solved, 10),
+                    report(2, None, PartStatus::Timeout, 0),
+                ],
+                day!(1),
+            );
+            assert_approx_eq!(res.total_nanos, 10_f64);
+            assert_eq!(res.part_1.unwrap(), "10.0ns");
+            assert_eq!(res.part_2.unwrap(), "timeout");
+        }
+
+        #[test]
+        fn collects_parse_times() {
+            let res = timing_from_reports(
+                &[
+                    report(PARSE_PART, None, PartStatus::Solved, 1_000),
+                    report(1, Some("1"), PartStatus::Solved, 10),
+                    report(2, Some("2"), PartStatus::Solved, 10),
+                ],
+                day!(1),
+            );
+            assert_approx_eq!(res.total_nanos, 1020_f64);
+            assert_eq!(res.parse.unwrap(), "1.0µs");
+            assert_eq!(res.part_1.unwrap(), "10.0ns");
+        }
     }
 }
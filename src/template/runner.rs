@@ -1,88 +1,340 @@
 /// Encapsulates code that interacts with solution functions.
+use std::any::Any;
 use std::fmt::Display;
-use std::hint::black_box;
-use std::io::{Write, stdout};
-use std::process::Output;
+use std::io::{Write, stdin, stdout};
+use std::panic::{self, AssertUnwindSafe};
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{env, process};
 
-use crate::template::ANSI_BOLD;
-use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, aoc_cli};
+use crate::template::answer::{Answer, IntoAnswer};
+use crate::template::answers::{self, Verdict};
+use crate::template::aoc_cli::SubmissionOutcome;
+use crate::template::backend;
+use crate::template::bench::{self, BenchConfig, BenchStats};
+use crate::template::heap::{self, HeapStats};
+use crate::template::input::InputSource;
+use crate::template::limits::{self, Limits};
+use crate::template::report::{OutputFormat, PARSE_PART, PartReport, PartStatus};
+use crate::template::submissions::{self, Submission};
+use crate::template::{ANSI_BOLD, ANSI_DIM, ANSI_RED};
+use crate::template::{ANSI_ITALIC, ANSI_RESET, Day};
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+pub fn run_part<I: Copy, R: IntoAnswer>(
+    func: impl Fn(I) -> R,
+    input: I,
+    day: Day,
+    part: u8,
+) -> PartReport {
+    let format = OutputFormat::from_args();
+    let bench_config = env::args()
+        .any(|x| x == "--time")
+        .then(BenchConfig::from_args);
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    start_part(day, part, format);
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    let (result, report) = measure(func, input, day, part, bench_config.as_ref(), |result| {
+        if format.is_human()
+            && let Ok(result) = result
+        {
+            print_result(&result.as_ref().map(report_value), &part_str, "");
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+            if bench_config.is_some() {
+                print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+                let _ = stdout().flush();
+            }
+        }
+    });
+
+    match format {
+        OutputFormat::Human if report.status.is_failure() => print_failure(&report),
+        OutputFormat::Human => {
+            print_result(
+                &report.answer,
+                &part_str,
+                &format_duration(&report.duration(), report.samples),
+            );
+            if let Some(answer) = &result {
+                print_recognized_art(answer);
+            }
+            print_stats(report.stats.as_ref());
+            print_heap(report.heap.as_ref());
+            print_verdict(&answers::check_input(&report, &InputSource::from_args()));
+        }
+        OutputFormat::Ndjson => println!("{}", report.to_json_line()),
+        OutputFormat::Json => {}
     }
+
+    if let Some(result) = result
+        && let Some((answer, SubmissionOutcome::Correct)) = submit_result(&result, day, part)
+    {
+        let stored = match InputSource::from_args() {
+            InputSource::Member(member) => answers::store_member(day, &member, part, &answer),
+            _ => answers::store(day, part, &answer),
+        };
+
+        match stored {
+            Ok(()) => println!("Recorded the answer to verify future runs against."),
+            Err(e) => eprintln!("Failed to record the answer: {e}"),
+        }
+    }
+
+    report
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
-fn run_timed<I: Copy, T>(
-    func: impl Fn(I) -> T,
+/// Run a solution part without printing anything and return its report.
+/// The part is benched if a `bench_config` is passed.
+pub fn measure_part<I: Copy, R: IntoAnswer>(
+    func: impl Fn(I) -> R,
     input: I,
-    hook: impl Fn(&T),
-) -> (T, Duration, u128) {
-    let timer = Instant::now();
-    let result = {
-        #[cfg(feature = "dhat-heap")]
-        let _profiler = dhat::Profiler::new_heap();
+    day: Day,
+    part: u8,
+    bench_config: Option<&BenchConfig>,
+) -> PartReport {
+    measure(func, input, day, part, bench_config, |_| {}).1
+}
 
-        func(input)
-    };
-    let base_time = timer.elapsed();
+/// Run the shared parse step of a solution and return its output together with its report.
+/// The parse step is timed and benched like a part, so its time is reported separately from the parts.
+/// Returns no output if the parse step panicked.
+pub fn run_parse<I: Copy, P>(func: impl Fn(I) -> P, input: I, day: Day) -> (Option<P>, PartReport) {
+    let format = OutputFormat::from_args();
+    let bench_config = env::args()
+        .any(|x| x == "--time")
+        .then(BenchConfig::from_args);
 
-    hook(&result);
+    start_part(day, PARSE_PART, format);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
-    } else {
-        (base_time, 1)
+    let (parsed, report) = measure_parse_with(func, input, day, bench_config.as_ref(), || {
+        if format.is_human() && bench_config.is_some() {
+            print!("Parse: > {ANSI_ITALIC}benching{ANSI_RESET}");
+            let _ = stdout().flush();
+        }
+    });
+
+    match format {
+        OutputFormat::Human if report.status.is_failure() => print_failure(&report),
+        OutputFormat::Human => {
+            print_parse(&format_duration(&report.duration(), report.samples));
+            print_stats(report.stats.as_ref());
+            print_heap(report.heap.as_ref());
+        }
+        OutputFormat::Ndjson => println!("{}", report.to_json_line()),
+        OutputFormat::Json => {}
+    }
+
+    (parsed, report)
+}
+
+/// Run the shared parse step of a solution without printing anything.
+/// The parse step is benched if a `bench_config` is passed.
+pub fn measure_parse<I: Copy, P>(
+    func: impl Fn(I) -> P,
+    input: I,
+    day: Day,
+    bench_config: Option<&BenchConfig>,
+) -> (Option<P>, PartReport) {
+    measure_parse_with(func, input, day, bench_config, || {})
+}
+
+/// Read the input selected on the command-line, see [`InputSource`], and print which input is used.
+/// Exits if the input can not be read.
+pub fn read_input(day: Day) -> String {
+    let source = InputSource::from_args();
+
+    let input = source.read(day).unwrap_or_else(|e| {
+        eprintln!("Failed to read input: {e}");
+        process::exit(1);
+    });
+
+    if OutputFormat::from_args().is_human() {
+        println!("{ANSI_DIM}Input: {}{ANSI_RESET}", source.path(day));
+    }
+
+    input
+}
+
+/// Announce a part to the parent process and apply its memory limit, see [`Limits`].
+fn start_part(day: Day, part: u8, format: OutputFormat) {
+    if format == OutputFormat::Ndjson {
+        let report = PartReport::without_answer(day, part, PartStatus::Running, None);
+        println!("{}", report.to_json_line());
+    }
+
+    let limits = Limits::from_args();
+
+    if !limits.memory_limits.is_empty()
+        && let Err(e) = limits::apply_memory_limit(limits.memory_limit(day, part))
+    {
+        eprintln!("Failed to apply memory limit: {e}");
+    }
+}
+
+/// Print the reports of all parts that ran if the JSON output format was requested.
+pub fn emit_reports(reports: &[PartReport]) {
+    if OutputFormat::from_args() == OutputFormat::Json {
+        let lines: Vec<String> = reports.iter().map(PartReport::to_json_line).collect();
+        println!("[{}]", lines.join(","));
+    }
+}
+
+fn measure<I: Copy, R: IntoAnswer>(
+    func: impl Fn(I) -> R,
+    input: I,
+    day: Day,
+    part: u8,
+    bench_config: Option<&BenchConfig>,
+    hook: impl Fn(&Result<Option<Answer>, String>),
+) -> (Option<Answer>, PartReport) {
+    let func = |input| func(input).into_answer();
+    let timed = run_timed(func, input, day, part, bench_config, hook, Result::is_ok);
+
+    let mut report = PartReport {
+        day,
+        part,
+        answer: None,
+        nanos: 0,
+        samples: 1,
+        stats: None,
+        status: PartStatus::Panicked,
+        error: None,
+        heap: None,
+    };
+
+    let (result, duration, stats, heap) = match timed {
+        Ok(timed) => timed,
+        Err(panic) => {
+            report.error = Some(panic);
+            return (None, report);
+        }
     };
 
-    (result, run.0, run.1)
+    report.nanos = duration.as_nanos();
+    report.samples = stats.map_or(1, |x| x.samples);
+    report.stats = stats;
+    report.heap = heap;
+
+    match result {
+        Ok(Some(answer)) => {
+            report.answer = Some(report_value(&answer));
+            report.status = PartStatus::Solved;
+            (Some(answer), report)
+        }
+        Ok(None) => {
+            report.status = PartStatus::Unsolved;
+            (None, report)
+        }
+        Err(error) => {
+            report.error = Some(error);
+            report.status = PartStatus::Failed;
+            (None, report)
+        }
+    }
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+fn measure_parse_with<I: Copy, P>(
+    func: impl Fn(I) -> P,
+    input: I,
+    day: Day,
+    bench_config: Option<&BenchConfig>,
+    hook: impl Fn(),
+) -> (Option<P>, PartReport) {
+    let timed = run_timed(
+        func,
+        input,
+        day,
+        PARSE_PART,
+        bench_config,
+        |_| hook(),
+        |_| true,
+    );
+
+    let mut report = PartReport {
+        day,
+        part: PARSE_PART,
+        answer: None,
+        nanos: 0,
+        samples: 1,
+        stats: None,
+        status: PartStatus::Panicked,
+        error: None,
+        heap: None,
+    };
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+    match timed {
+        Ok((parsed, duration, stats, heap)) => {
+            report.nanos = duration.as_nanos();
+            report.samples = stats.map_or(1, |x| x.samples);
+            report.stats = stats;
+            report.heap = heap;
+            report.status = PartStatus::Solved;
+            (Some(parsed), report)
+        }
+        Err(panic) => {
+            report.error = Some(panic);
+            (None, report)
+        }
+    }
+}
 
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+/// Run a solution part. The behavior differs depending on whether a [`BenchConfig`] is passed:
+///  1. without it, the function is executed once.
+///  2. with it, the function is benched according to the config (by default 3 warm-up rounds,
+///     then approx. 1 second of execution time or 10 samples, whatever takes longer.)
+///     Results for which `should_bench` returns false are not benched.
+///
+/// The allocations of the first run are recorded with the `dhat-heap` feature, see [`heap::profile`].
+/// Returns the panic message if the function panicked.
+fn run_timed<I: Copy, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    day: Day,
+    part: u8,
+    bench_config: Option<&BenchConfig>,
+    hook: impl Fn(&T),
+    should_bench: impl Fn(&T) -> bool,
+) -> Result<(T, Duration, Option<BenchStats>, Option<HeapStats>), String> {
+    let timer = Instant::now();
+    let (result, heap) = heap::profile(day, part, || {
+        panic::catch_unwind(AssertUnwindSafe(|| func(input)))
+    });
+    let result = result.map_err(panic_message)?;
+    let base_time = timer.elapsed();
 
-    let mut timers: Vec<Duration> = vec![];
+    hook(&result);
 
-    for _ in 0..bench_iterations {
-        let timer = Instant::now();
-        black_box(func(black_box(input)));
-        timers.push(timer.elapsed());
+    match bench_config {
+        Some(config) if should_bench(&result) => {
+            let samples = bench::sample(func, input, config);
+            let stats = BenchStats::from_samples(&samples)
+                .expect("the bench config enforces at least one sample");
+            Ok((result, stats.mean_duration(), Some(stats), heap))
+        }
+        _ => Ok((result, base_time, None, heap)),
     }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(ToString::to_string)
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic payload".into())
+}
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+/// The answer as written to reports: the recognized letters of ASCII art, or the answer as printed.
+fn report_value(answer: &Answer) -> String {
+    answer.submission().unwrap_or_else(|_| answer.to_string())
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+/// Print ASCII art below its recognized letters, so the recognition can be double-checked.
+fn print_recognized_art(answer: &Answer) {
+    if answer.is_art() && answer.submission().is_ok() {
+        println!("{ANSI_DIM}{}{ANSI_RESET}", answer.to_string().trim_end());
+    }
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
+pub(crate) fn format_duration(duration: &Duration, samples: u128) -> String {
     if samples == 1 {
         format!(" ({duration:.1?})")
     } else {
@@ -90,7 +342,47 @@ fn format_duration(duration: &Duration, samples: u128) -> String {
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+/// Print benchmark statistics below a result, if the part was benched.
+pub(crate) fn print_stats(stats: Option<&BenchStats>) {
+    if let Some(stats) = stats {
+        println!("  {ANSI_DIM}{}{ANSI_RESET}", stats.summary());
+    }
+}
+
+/// Print allocation statistics below a result, if they were recorded.
+pub(crate) fn print_heap(heap: Option<&HeapStats>) {
+    if let Some(heap) = heap {
+        println!("  {ANSI_DIM}{}{ANSI_RESET}", heap.summary());
+    }
+}
+
+/// Print a failed or panicked part together with its error.
+pub(crate) fn print_failure(report: &PartReport) {
+    let name = report.label();
+    print!("\r");
+    println!("{name}: {ANSI_RED}✖ {}{ANSI_RESET}", report.status.as_str());
+
+    if let Some(error) = &report.error {
+        let location = format!("day {}, {}", report.day, name.to_lowercase());
+        for line in format!("{location}: {error}").lines() {
+            println!("  {ANSI_RED}{line}{ANSI_RESET}");
+        }
+    }
+}
+
+/// Print a warning below a result if it differs from the recorded answer.
+pub(crate) fn print_verdict(verdict: &Verdict) {
+    if let Verdict::Incorrect { expected } = verdict {
+        println!("  {ANSI_RED}✖ differs from recorded answer: {expected}{ANSI_RESET}");
+    }
+}
+
+pub(crate) fn print_parse(duration_str: &str) {
+    print!("\r");
+    println!("{ANSI_DIM}Parse:{duration_str}{ANSI_RESET}");
+}
+
+pub(crate) fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
@@ -127,12 +419,14 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
 
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+///  2. the backend is configured, see [`backend::select`].
+///  3. the part ran against a puzzle input, i.e. not against an example or another file.
+///  4. the answer is not known to be wrong from previous submissions.
+///  5. the answer does not look suspicious, or submitting it was confirmed.
+///
+/// Every submission is appended to the submission log together with its outcome.
+/// Returns the submitted answer together with the outcome.
+fn submit_result(result: &Answer, day: Day, part: u8) -> Option<(String, SubmissionOutcome)> {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
@@ -155,13 +449,72 @@ fn submit_result<T: Display>(
         return None;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
+    if cfg!(debug_assertions) {
+        eprintln!("Refusing to submit from a debug build. Try running with \"--release\".");
+        process::exit(1);
+    }
+
+    // NOTE: a team member's input is the puzzle input of that member's account.
+    let source = InputSource::from_args();
+    if !matches!(source, InputSource::Puzzle | InputSource::Member(_)) {
+        eprintln!("Refusing to submit: the part ran against the {source}, not a puzzle input.");
+        return None;
+    }
+
+    let answer = match result.submission() {
+        Ok(answer) => answer,
+        Err(reason) => {
+            eprintln!("Refusing to submit: {reason}");
+            return None;
+        }
+    };
+
+    let backend = backend::select().unwrap_or_else(|e| {
+        eprintln!("{e}");
         process::exit(1);
+    });
+
+    let log = submissions::read_from_file().unwrap_or_else(|e| {
+        eprintln!("Failed to read submission log: {e}");
+        process::exit(1);
+    });
+
+    if let Err(reason) = submissions::check(&log, day, part, &answer) {
+        eprintln!("Refusing to submit: {reason}");
+        return None;
+    }
+
+    if let Some(reason) = submissions::suspicion(&answer)
+        && !confirm(&format!(
+            "The answer looks suspicious, {reason}. Submit anyway?"
+        ))
+    {
+        println!("Submission cancelled.");
+        return None;
+    }
+
+    println!("Submitting result...");
+
+    let outcome = match backend.submit(day, part, &answer) {
+        Ok(outcome) => outcome,
+        Err(e) => {
+            eprintln!("Failed to submit: {e}");
+            return None;
+        }
+    };
+
+    if let Err(e) = submissions::append(&Submission::new(day, part, &answer, outcome)) {
+        eprintln!("Failed to append submission to log: {e}");
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    Some((answer, outcome))
+}
+
+/// Ask a yes/no question on the command-line. Anything but "y" counts as no.
+fn confirm(question: &str) -> bool {
+    print!("{question} [y/N] ");
+    let _ = stdout().flush();
+
+    let mut line = String::new();
+    stdin().read_line(&mut line).is_ok() && line.trim().eq_ignore_ascii_case("y")
 }
@@ -0,0 +1,247 @@
+/// Module that keeps a log of every submitted answer and the outcome of its submission.
+/// The log is used to refuse submissions that are known to be wrong before they reach the website.
+use std::{
+    collections::HashMap,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    str::FromStr,
+    time::SystemTime,
+};
+use tinyjson::JsonValue;
+
+use crate::template::aoc_cli::SubmissionOutcome;
+use crate::template::history::format_date;
+use crate::template::{Day, Year};
+
+fn get_log_path() -> String {
+    format!("./{}/submissions.jsonl", Year::current().data_dir())
+}
+
+/// A single submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub day: Day,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: SubmissionOutcome,
+    /// UTC date and time in ISO 8601 format.
+    pub date: String,
+}
+
+impl Submission {
+    pub fn new(day: Day, part: u8, answer: &str, outcome: SubmissionOutcome) -> Self {
+        Self {
+            day,
+            part,
+            answer: answer.to_string(),
+            outcome,
+            date: format_date(SystemTime::now()),
+        }
+    }
+}
+
+/// Append a submission to the log.
+pub fn append(submission: &Submission) -> Result<(), io::Error> {
+    let line = JsonValue::from(submission)
+        .stringify()
+        .map_err(|e| io::Error::other(e.to_string()))?;
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(get_log_path())?;
+
+    writeln!(file, "{line}")
+}
+
+/// Read all submissions from the log. If not present, returns no submissions.
+pub fn read_from_file() -> Result<Vec<Submission>, String> {
+    let Ok(content) = fs::read_to_string(get_log_path()) else {
+        return Ok(vec![]);
+    };
+
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let json =
+                JsonValue::from_str(line).or(Err("submission log contains invalid JSON."))?;
+            Submission::try_from(&json)
+        })
+        .collect()
+}
+
+/// Check an answer against the previous submissions of its part.
+/// Returns the reason if the answer is known to be wrong.
+pub fn check(log: &[Submission], day: Day, part: u8, answer: &str) -> Result<(), String> {
+    let previous = log
+        .iter()
+        .filter(|x| x.day == day && x.part == part && x.outcome.is_rejection());
+
+    let value = answer.parse::<i128>().ok();
+
+    for submission in previous {
+        if submission.answer == answer {
+            return Err(format!(
+                "{answer} was already submitted and rejected ({}).",
+                submission.outcome
+            ));
+        }
+
+        let (Some(value), Ok(bound)) = (value, submission.answer.parse::<i128>()) else {
+            continue;
+        };
+
+        match submission.outcome {
+            SubmissionOutcome::TooHigh if value >= bound => {
+                return Err(format!("{bound} was already too high."));
+            }
+            SubmissionOutcome::TooLow if value <= bound => {
+                return Err(format!("{bound} was already too low."));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns a description if an answer looks like it could be the result of a bug.
+pub fn suspicion(answer: &str) -> Option<&'static str> {
+    let answer = answer.trim();
+
+    if answer.contains('\n') {
+        Some("it spans multiple lines")
+    } else if answer.parse::<i128>().is_ok_and(|x| x == 0) {
+        Some("it is zero")
+    } else if answer.parse::<i128>().is_ok_and(|x| x < 0) {
+        Some("it is negative")
+    } else {
+        None
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Submission> for JsonValue {
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "day".into(),
+            JsonValue::Number(f64::from(value.day.into_inner())),
+        );
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "outcome".into(),
+            JsonValue::String(value.outcome.as_str().into()),
+        );
+        map.insert("date".into(), JsonValue::String(value.date.clone()));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let string = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<String>())
+                .ok_or(format!("Expected submission.{key} to be a string."))
+        };
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected submission.{key} to be a number."))
+        };
+
+        Ok(Submission {
+            day: Day::new(number("day")? as u8)
+                .ok_or("Expected submission.day to be a valid day.")?,
+            part: number("part")? as u8,
+            answer: string("answer")?.clone(),
+            outcome: string("outcome")?.parse()?,
+            date: string("date")?.clone(),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::{Submission, check, suspicion};
+    use crate::{day, template::aoc_cli::SubmissionOutcome};
+
+    fn submission(part: u8, answer: &str, outcome: SubmissionOutcome) -> Submission {
+        Submission {
+            day: day!(1),
+            part,
+            answer: answer.into(),
+            outcome,
+            date: "2024-12-01T05:00:00Z".into(),
+        }
+    }
+
+    #[test]
+    fn refuses_rejected_answers() {
+        let log = [submission(1, "abc", SubmissionOutcome::Wrong)];
+        assert!(check(&log, day!(1), 1, "abc").is_err());
+        assert!(check(&log, day!(1), 1, "abd").is_ok());
+        assert!(check(&log, day!(1), 2, "abc").is_ok());
+        assert!(check(&log, day!(2), 1, "abc").is_ok());
+    }
+
+    #[test]
+    fn refuses_answers_outside_bounds() {
+        let log = [
+            submission(1, "100", SubmissionOutcome::TooHigh),
+            submission(1, "10", SubmissionOutcome::TooLow),
+        ];
+        assert!(check(&log, day!(1), 1, "150").is_err());
+        assert!(check(&log, day!(1), 1, "100").is_err());
+        assert!(check(&log, day!(1), 1, "5").is_err());
+        assert!(check(&log, day!(1), 1, "50").is_ok());
+    }
+
+    #[test]
+    fn ignores_unjudged_submissions() {
+        let log = [submission(
+            1,
+            "42",
+            SubmissionOutcome::RateLimited { wait: None },
+        )];
+        assert!(check(&log, day!(1), 1, "42").is_ok());
+    }
+
+    #[test]
+    fn detects_suspicious_answers() {
+        assert!(suspicion("0").is_some());
+        assert!(suspicion("-12").is_some());
+        assert!(suspicion("#..\n.##").is_some());
+        assert!(suspicion("42").is_none());
+        assert!(suspicion("abc").is_none());
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let entry = submission(2, "42", SubmissionOutcome::TooLow);
+        let json: JsonValue = JsonValue::from(&entry)
+            .stringify()
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert_eq!(Submission::try_from(&json).unwrap(), entry);
+    }
+}
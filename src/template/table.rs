@@ -0,0 +1,110 @@
+/// Aligned tables of part results, e.g. the answer matrix of team mode.
+use crate::template::{
+    ANSI_GREEN, ANSI_RED, ANSI_RESET,
+    answers::{self, Verdict},
+    input::InputSource,
+    report::PartReport,
+};
+
+/// A cell of a table. The width of a cell is measured without its color.
+pub struct Cell {
+    text: String,
+    color: Option<&'static str>,
+}
+
+impl Cell {
+    pub fn plain(text: impl Into<String>) -> Self {
+        Cell {
+            text: text.into(),
+            color: None,
+        }
+    }
+
+    pub fn colored(text: impl Into<String>, color: &'static str) -> Self {
+        Cell {
+            text: text.into(),
+            color: Some(color),
+        }
+    }
+
+    fn width(&self) -> usize {
+        self.text.chars().count()
+    }
+
+    fn render(&self, width: usize) -> String {
+        let padding = " ".repeat(width.saturating_sub(self.width()));
+        match self.color {
+            Some(color) => format!("{color}{}{ANSI_RESET}{padding}", self.text),
+            None => format!("{}{padding}", self.text),
+        }
+    }
+}
+
+/// The cell of a part, e.g. `42 ✔ (1.2ms)`. The answer is checked against the recorded answer for `source`.
+/// Part `0` is the shared parse step, which only shows its time.
+pub fn part_cell(reports: &[PartReport], part: u8, source: &InputSource) -> Cell {
+    let parse = reports.iter().find(|report| report.is_parse());
+    let Some(report) = reports.iter().find(|report| report.part == part) else {
+        return match parse {
+            Some(parse) if parse.status.is_failure() => {
+                Cell::colored(format!("✖ parse {}", parse.status.as_str()), ANSI_RED)
+            }
+            _ => Cell::plain("-"),
+        };
+    };
+
+    if report.status.is_failure() {
+        return Cell::colored(format!("✖ {}", report.status.as_str()), ANSI_RED);
+    }
+
+    let duration = format!("({:.1?})", report.duration());
+
+    if report.is_parse() {
+        return Cell::plain(duration);
+    }
+
+    let Some(answer) = &report.answer else {
+        return Cell::plain("✖");
+    };
+
+    // NOTE: ASCII art that could not be recognized does not fit into a cell.
+    let answer = if answer.contains('\n') {
+        "▼ ascii art"
+    } else {
+        answer.as_str()
+    };
+
+    match answers::check_input(report, source) {
+        Verdict::Correct => Cell::colored(format!("{answer} ✔ {duration}"), ANSI_GREEN),
+        Verdict::Incorrect { expected } => Cell::colored(
+            format!("{answer} ✖ {duration}, expected {expected}"),
+            ANSI_RED,
+        ),
+        Verdict::Unknown => Cell::plain(format!("{answer} {duration}")),
+    }
+}
+
+/// Render rows of cells with aligned columns. All rows must have the same number of cells.
+pub fn render(rows: &[Vec<Cell>]) -> Vec<String> {
+    let columns = rows.first().map_or(0, Vec::len);
+    let widths: Vec<usize> = (0..columns)
+        .map(|column| {
+            rows.iter()
+                .map(|row| row[column].width())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    rows.iter()
+        .map(|row| {
+            row.iter()
+                .zip(&widths)
+                .map(|(cell, width)| cell.render(*width))
+                .collect::<Vec<_>>()
+                .join("   ")
+                .trim_end()
+                .to_string()
+        })
+        .collect()
+}
@@ -0,0 +1,182 @@
+/// Team mode: a day's puzzle inputs can be a directory of named inputs, one per team member,
+/// e.g. `data/inputs/05/alice.txt`. Solutions then run against every input and their answers are
+/// printed as a matrix, cross-checked against each member's recorded answers.
+use std::fs;
+
+use crate::template::{
+    ANSI_DIM, Day, Year,
+    input::InputSource,
+    report::PartReport,
+    table::{self, Cell},
+};
+
+/// Reports of the parts that ran against a team member's input.
+#[derive(Debug, Clone)]
+pub struct MemberReports {
+    pub member: String,
+    pub reports: Vec<PartReport>,
+}
+
+/// The team members with an input for `day`, sorted by name.
+/// Returns no members if the day's input is a single file.
+pub fn members(day: Day) -> Vec<String> {
+    let dir = format!("{}/inputs/{day}", Year::current().data_dir());
+
+    let Ok(entries) = fs::read_dir(dir) else {
+        return vec![];
+    };
+
+    let mut members: Vec<String> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|x| x == "txt"))
+        .filter_map(|path| Some(path.file_stem()?.to_str()?.to_string()))
+        .collect();
+
+    members.sort_unstable();
+    members
+}
+
+/// Format the answers of all members as a matrix with a row per member and a column per part.
+fn matrix(members: &[MemberReports]) -> Vec<String> {
+    let parts: Vec<u8> = [1, 2]
+        .into_iter()
+        .filter(|part| {
+            members
+                .iter()
+                .flat_map(|member| &member.reports)
+                .any(|report| report.part == *part)
+        })
+        .collect();
+
+    let mut rows: Vec<Vec<Cell>> = vec![
+        std::iter::once(Cell::colored("Input", ANSI_DIM))
+            .chain(
+                parts
+                    .iter()
+                    .map(|part| Cell::colored(format!("Part {part}"), ANSI_DIM)),
+            )
+            .collect(),
+    ];
+
+    for member in members {
+        let source = InputSource::Member(member.member.clone());
+        rows.push(
+            std::iter::once(Cell::plain(member.member.clone()))
+                .chain(
+                    parts
+                        .iter()
+                        .map(|part| table::part_cell(&member.reports, *part, &source)),
+                )
+                .collect(),
+        );
+    }
+
+    table::render(&rows)
+}
+
+/// Print the answers of all members as a matrix, see [`matrix`].
+pub fn print_matrix(members: &[MemberReports]) {
+    matrix(members).iter().for_each(|line| println!("{line}"));
+}
+
+/// The reports of all parts that failed, with the member's name prepended to their error.
+pub fn failures(members: &[MemberReports]) -> Vec<PartReport> {
+    members
+        .iter()
+        .flat_map(|member| {
+            member
+                .reports
+                .iter()
+                .filter(|report| report.status.is_failure())
+                .map(|report| PartReport {
+                    error: Some(format!(
+                        "[{}] {}",
+                        member.member,
+                        report.error.as_deref().unwrap_or(report.status.as_str())
+                    )),
+                    ..report.clone()
+                })
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{MemberReports, failures, matrix};
+    use crate::template::{
+        report::{PARSE_PART, PartReport, PartStatus},
+        test_support::{report, strip_colors},
+    };
+
+    #[test]
+    fn formats_matrix() {
+        let members = [
+            MemberReports {
+                member: "alice".into(),
+                reports: vec![
+                    report(1, Some("42"), PartStatus::Solved, 1_500),
+                    report(2, Some("1337"), PartStatus::Solved, 1_500),
+                ],
+            },
+            MemberReports {
+                member: "bob".into(),
+                reports: vec![
+                    report(1, Some("7"), PartStatus::Solved, 1_500),
+                    report(2, None, PartStatus::Panicked, 1_500),
+                ],
+            },
+        ];
+
+        let lines: Vec<String> = matrix(&members).iter().map(|x| strip_colors(x)).collect();
+        assert_eq!(
+            lines,
+            [
+                "Input   Part 1       Part 2",
+                "alice   42 (1.5µs)   1337 (1.5µs)",
+                "bob     7 (1.5µs)    ✖ panicked",
+            ]
+        );
+    }
+
+    #[test]
+    fn shows_failed_parse_steps() {
+        let members = [MemberReports {
+            member: "alice".into(),
+            reports: vec![
+                report(PARSE_PART, None, PartStatus::Panicked, 1_500),
+                report(1, Some("1"), PartStatus::Solved, 1_500),
+            ],
+        }];
+
+        let lines: Vec<String> = matrix(&members).iter().map(|x| strip_colors(x)).collect();
+        assert_eq!(lines[1], "alice   1 (1.5µs)");
+
+        let members = [
+            members[0].clone(),
+            MemberReports {
+                member: "bob".into(),
+                reports: vec![report(2, Some("2"), PartStatus::Solved, 1_500)],
+            },
+        ];
+        let lines: Vec<String> = matrix(&members).iter().map(|x| strip_colors(x)).collect();
+        assert_eq!(lines[1], "alice   1 (1.5µs)   ✖ parse panicked");
+    }
+
+    #[test]
+    fn labels_failures_with_members() {
+        let members = [MemberReports {
+            member: "bob".into(),
+            reports: vec![PartReport {
+                error: Some("oops".into()),
+                ..report(2, None, PartStatus::Panicked, 1_500)
+            }],
+        }];
+
+        let failures = failures(&members);
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].error.as_deref(), Some("[bob] oops"));
+    }
+}
@@ -0,0 +1,39 @@
+/// Helpers shared by the tests of several modules.
+use crate::day;
+use crate::template::Day;
+use crate::template::report::{PartReport, PartStatus};
+use crate::template::timings::Timing;
+use crate::template::{ANSI_BOLD, ANSI_DIM, ANSI_GREEN, ANSI_RED, ANSI_RESET};
+
+/// The report of a part of day 1 that ran for `nanos`.
+pub fn report(part: u8, answer: Option<&str>, status: PartStatus, nanos: u128) -> PartReport {
+    PartReport {
+        answer: answer.map(Into::into),
+        nanos,
+        ..PartReport::without_answer(day!(1), part, status, None)
+    }
+}
+
+/// The timing of a day that only records its parts and total.
+pub fn timing(day: Day, part_1: Option<&str>, part_2: Option<&str>, total_nanos: f64) -> Timing {
+    Timing {
+        day,
+        parse: None,
+        part_1: part_1.map(Into::into),
+        part_2: part_2.map(Into::into),
+        total_nanos,
+        part_1_stats: None,
+        part_2_stats: None,
+        parse_stats: None,
+        parse_heap: None,
+        part_1_heap: None,
+        part_2_heap: None,
+    }
+}
+
+/// Remove the colors of a printed line, so it can be compared as plain text.
+pub fn strip_colors(line: &str) -> String {
+    [ANSI_BOLD, ANSI_DIM, ANSI_GREEN, ANSI_RED, ANSI_RESET]
+        .iter()
+        .fold(line.to_string(), |line, color| line.replace(color, ""))
+}
@@ -1,17 +1,70 @@
 use std::{collections::HashMap, fs, io::Error, str::FromStr};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
-
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+use crate::template::{
+    Day, Year,
+    bench::{BenchStats, stats_from_json, stats_to_json},
+    heap::{HeapStats, heap_from_json, heap_to_json},
+    report::PartStatus,
+};
+
+fn get_timings_path() -> String {
+    format!("./{}/timings.json", Year::current().data_dir())
+}
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
 pub struct Timing {
     pub day: Day,
+    /// Time spent in the shared parse step, if the solution has one.
+    pub parse: Option<String>,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
     pub total_nanos: f64,
+    /// Benchmark statistics of part 1, if it was benched.
+    pub part_1_stats: Option<BenchStats>,
+    /// Benchmark statistics of part 2, if it was benched.
+    pub part_2_stats: Option<BenchStats>,
+    /// Benchmark statistics of the parse step, if it was benched.
+    pub parse_stats: Option<BenchStats>,
+    /// Allocations of the parse step, if they were recorded with `--dhat`.
+    pub parse_heap: Option<HeapStats>,
+    /// Allocations of part 1, if they were recorded with `--dhat`.
+    pub part_1_heap: Option<HeapStats>,
+    /// Allocations of part 2, if they were recorded with `--dhat`.
+    pub part_2_heap: Option<HeapStats>,
+}
+
+impl Timing {
+    /// The status of a part that failed instead of being timed, e.g. [`PartStatus::Panicked`] or [`PartStatus::Timeout`].
+    /// Part `0` is the shared parse step.
+    pub fn failure(&self, part: u8) -> Option<PartStatus> {
+        let formatted = match part {
+            0 => &self.parse,
+            1 => &self.part_1,
+            2 => &self.part_2,
+            _ => return None,
+        };
+
+        formatted
+            .as_deref()?
+            .parse::<PartStatus>()
+            .ok()
+            .filter(|status| status.is_failure())
+    }
+
+    /// Allocations of the whole day: the sum of all steps, with the highest peak of any step.
+    /// Returns `None` if no allocations were recorded.
+    pub fn heap(&self) -> Option<HeapStats> {
+        [self.parse_heap, self.part_1_heap, self.part_2_heap]
+            .into_iter()
+            .flatten()
+            .reduce(|a, b| HeapStats {
+                total_bytes: a.total_bytes + b.total_bytes,
+                total_blocks: a.total_blocks + b.total_blocks,
+                peak_bytes: a.peak_bytes.max(b.peak_bytes),
+            })
+    }
 }
 
 /// Represents benchmark times for a set of days.
@@ -25,13 +78,13 @@ impl Timings {
     /// Dehydrate timings to a JSON file.
     pub fn store_file(&self) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        let mut file = fs::File::create(get_timings_path())?;
         json.format_to(&mut file)
     }
 
     /// Rehydrate timings from a JSON file. If not present, returns empty timings.
     pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
+        fs::read_to_string(get_timings_path())
             .map_err(|x| x.to_string())
             .and_then(Timings::try_from)
             .unwrap_or_default()
@@ -51,7 +104,7 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.day);
         Timings { data }
     }
 
@@ -60,10 +113,15 @@ impl Timings {
         self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
-    pub fn is_day_complete(&self, day: Day) -> bool {
-        self.data
-            .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+    /// Whether both parts of a day were timed. Parts that failed do not count.
+    pub fn is_day_complete(&self, day: &Day) -> bool {
+        self.data.iter().any(|t| {
+            t.day == *day
+                && t.part_1.is_some()
+                && t.part_2.is_some()
+                && t.failure(1).is_none()
+                && t.failure(2).is_none()
+        })
     }
 }
 
@@ -87,7 +145,14 @@ impl TryFrom<String> for Timings {
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
         let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+        Timings::try_from(&json)
+    }
+}
+
+impl TryFrom<&JsonValue> for Timings {
+    type Error = String;
 
+    fn try_from(json: &JsonValue) -> Result<Self, Self::Error> {
         let json_data = json
             .get::<HashMap<String, JsonValue>>()
             .ok_or("expected JSON document to be an object.")?
@@ -133,6 +198,32 @@ impl From<&Timing> for JsonValue {
             },
         );
 
+        if let Some(parse) = &value.parse {
+            map.insert("parse".into(), JsonValue::String(parse.clone()));
+        }
+
+        map.insert(
+            "part_1_stats".into(),
+            stats_to_json(value.part_1_stats.as_ref()),
+        );
+        map.insert(
+            "part_2_stats".into(),
+            stats_to_json(value.part_2_stats.as_ref()),
+        );
+        map.insert(
+            "parse_stats".into(),
+            stats_to_json(value.parse_stats.as_ref()),
+        );
+        map.insert("parse_heap".into(), heap_to_json(value.parse_heap.as_ref()));
+        map.insert(
+            "part_1_heap".into(),
+            heap_to_json(value.part_1_heap.as_ref()),
+        );
+        map.insert(
+            "part_2_heap".into(),
+            heap_to_json(value.part_2_heap.as_ref()),
+        );
+
         JsonValue::Object(map)
     }
 }
@@ -161,16 +252,36 @@ impl TryFrom<&JsonValue> for Timing {
             .map(|v| if v.is_null() { None } else { v.get::<String>() })
             .ok_or("Expected timing.part_2 to be null or string.")?;
 
+        // NOTE: the parse step is optional, solutions without one don't record it.
+        let parse = json.get("parse").and_then(|v| v.get::<String>()).cloned();
+
         let total_nanos = json
             .get("total_nanos")
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        // NOTE: stats are optional to support timings stored before they were recorded.
+        let part_1_stats = stats_from_json(json, "part_1_stats")?;
+        let part_2_stats = stats_from_json(json, "part_2_stats")?;
+        let parse_stats = stats_from_json(json, "parse_stats")?;
+
+        // NOTE: allocations are only recorded when timing with `--dhat`.
+        let parse_heap = heap_from_json(json, "parse_heap")?;
+        let part_1_heap = heap_from_json(json, "part_1_heap")?;
+        let part_2_heap = heap_from_json(json, "part_2_heap")?;
+
         Ok(Timing {
             day,
+            parse,
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
             total_nanos,
+            part_1_stats,
+            part_2_stats,
+            parse_stats,
+            parse_heap,
+            part_1_heap,
+            part_2_heap,
         })
     }
 }
@@ -179,31 +290,16 @@ impl TryFrom<&JsonValue> for Timing {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use crate::day;
+    use crate::{day, template::test_support::timing};
 
-    use super::{Timing, Timings};
+    use super::Timings;
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
-                Timing {
-                    day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
-                    total_nanos: 3e+10,
-                },
-                Timing {
-                    day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
-                    total_nanos: 7e+10,
-                },
-                Timing {
-                    day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: None,
-                    total_nanos: 4e+10,
-                },
+                timing(day!(1), Some("10ms"), Some("20ms"), 3e+10),
+                timing(day!(2), Some("30ms"), Some("40ms"), 7e+10),
+                timing(day!(4), Some("40ms"), None, 4e+10),
             ],
         }
     }
@@ -223,6 +319,18 @@ mod tests {
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn handles_json_timings_with_stats() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "part_1_stats": { "samples": 10, "mean": 1000000, "min": 900000, "median": 950000, "p95": 1200000, "std_dev": 5000, "outliers": 1 }, "part_2_stats": null }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            let stats = timing.part_1_stats.unwrap();
+            assert_eq!(stats.samples, 10);
+            assert_eq!(stats.median, 950_000_f64);
+            assert_eq!(stats.outliers, 1);
+            assert_eq!(timing.part_2_stats, None);
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -271,18 +379,13 @@ mod tests {
     mod is_day_complete {
         use crate::{
             day,
-            template::timings::{Timing, Timings},
+            template::{test_support::timing, timings::Timings},
         };
 
         #[test]
         fn handles_completed_days() {
             let timings = Timings {
-                data: vec![Timing {
-                    day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: Some("2ms".into()),
-                    total_nanos: 3_000_000_000_f64,
-                }],
+                data: vec![timing(day!(1), Some("1ms"), Some("2ms"), 3_000_000_000_f64)],
             };
 
             assert_eq!(timings.is_day_complete(&day!(1)), true);
@@ -291,12 +394,7 @@ mod tests {
         #[test]
         fn handles_partial_days() {
             let timings = Timings {
-                data: vec![Timing {
-                    day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: None,
-                    total_nanos: 1_000_000_000_f64,
-                }],
+                data: vec![timing(day!(1), Some("1ms"), None, 1_000_000_000_f64)],
             };
 
             assert_eq!(timings.is_day_complete(&day!(1)), false);
@@ -305,12 +403,21 @@ mod tests {
         #[test]
         fn handles_uncompleted_days() {
             let timings = Timings {
-                data: vec![Timing {
-                    day: day!(1),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0.0,
-                }],
+                data: vec![timing(day!(1), None, None, 0.0)],
+            };
+
+            assert_eq!(timings.is_day_complete(&day!(1)), false);
+        }
+
+        #[test]
+        fn handles_killed_parts() {
+            let timings = Timings {
+                data: vec![timing(
+                    day!(1),
+                    Some("1ms"),
+                    Some("timeout"),
+                    1_000_000_000_f64,
+                )],
             };
 
             assert_eq!(timings.is_day_complete(&day!(1)), false);
@@ -320,7 +427,7 @@ mod tests {
     mod merge {
         use crate::{
             day,
-            template::timings::{Timing, Timings},
+            template::{test_support::timing, timings::Timings},
         };
 
         use super::get_mock_timings;
@@ -329,12 +436,7 @@ mod tests {
         fn handles_disjunct_timings() {
             let timings = get_mock_timings();
             let other = Timings {
-                data: vec![Timing {
-                    day: day!(3),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0_f64,
-                }],
+                data: vec![timing(day!(3), None, None, 0_f64)],
             };
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 4);
@@ -349,12 +451,7 @@ mod tests {
             let timings = get_mock_timings();
 
             let other = Timings {
-                data: vec![Timing {
-                    day: day!(2),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0_f64,
-                }],
+                data: vec![timing(day!(2), None, None, 0_f64)],
             };
             let merged = timings.merge(&other);
 
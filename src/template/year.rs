@@ -0,0 +1,214 @@
+use std::env;
+use std::error::Error;
+use std::fmt::Display;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use crate::template::Day;
+
+/// The event that is used when `AOC_YEAR` is not set at compile time.
+const FALLBACK_YEAR: u16 = 2025;
+
+/// Starting with 2025, events only have twelve puzzles.
+const LAST_25_DAY_EVENT: u16 = 2024;
+
+/// A valid Advent of Code event, identified by its year (i.e. 2015 or later).
+///
+/// The event knows how many puzzles it has and where its solutions and data live.
+/// By default, all files live in the flat `src/bin/` and `data/` directories.
+/// If a `src/bin/<year>/` directory exists, the event switches to a per-year layout
+/// so that one repository can hold solutions for multiple years:
+///
+/// - solutions live in `src/bin/<year>/DD.rs` and are registered as `<year>-DD` binaries.
+/// - inputs, examples and puzzles live in `data/<year>/`.
+///
+/// ```
+/// # use advent_of_code::template::Year;
+/// let year = Year::new(2025).unwrap();
+/// assert_eq!(year.day_count(), 12)
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+/// The event of the running solution, see [`Year::set_current`].
+static CURRENT: OnceLock<Year> = OnceLock::new();
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if an event took place that year,
+    /// returns [`None`] otherwise.
+    pub const fn new(year: u16) -> Option<Self> {
+        if year < 2015 {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    /// Returns the event that commands and solutions operate on: the event of the running solution,
+    /// see [`Year::set_current`], or else the event configured via `AOC_YEAR`.
+    ///
+    /// If no event was set, the event is derived from the name of the executable, so that tests of
+    /// solutions in `src/bin/<year>/` (e.g. `2024_13-<hash>`) read the data of their own event.
+    pub fn current() -> Self {
+        *CURRENT.get_or_init(|| {
+            let exe = env::current_exe().ok();
+            let name = exe
+                .as_deref()
+                .and_then(Path::file_stem)
+                .and_then(|x| x.to_str());
+            Self::of_bin(name.map(|x| x.replace('_', "-")).as_deref())
+        })
+    }
+
+    /// Sets the event of the running solution, so that its days are checked against and its data is read
+    /// from its own event. Has no effect once the event was set or read.
+    pub fn set_current(year: Year) {
+        let _ = CURRENT.set(year);
+    }
+
+    /// Returns the event of a solution binary from its name: `<year>-DD` for events with their own directory,
+    /// or else the configured event. Pass `option_env!("CARGO_BIN_NAME")` to get the event of the current binary.
+    pub const fn of_bin(name: Option<&str>) -> Self {
+        if let Some(name) = name
+            && name.len() > 5
+            && name.as_bytes()[4] == b'-'
+            && let Ok(year) = std::str::from_utf8(name.as_bytes().split_at(4).0)
+            && let Some(year) = parse_year(year)
+        {
+            return year;
+        }
+
+        Self::configured()
+    }
+
+    /// Returns the event configured via the `AOC_YEAR` environment variable at compile time.
+    pub const fn configured() -> Self {
+        match option_env!("AOC_YEAR") {
+            Some(year) => match parse_year(year) {
+                Some(year) => year,
+                None => panic!("AOC_YEAR is not a valid year"),
+            },
+            None => Self(FALLBACK_YEAR),
+        }
+    }
+
+    /// Returns the number of puzzles of this event.
+    pub const fn day_count(self) -> u8 {
+        if self.0 > LAST_25_DAY_EVENT { 12 } else { 25 }
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// Whether this event uses the per-year directory layout.
+    pub fn has_own_directory(self) -> bool {
+        Path::new(&format!("src/bin/{self}")).is_dir()
+    }
+
+    /// Name of the binary that holds the solution for a day.
+    pub fn bin_name(self, day: Day) -> String {
+        if self.has_own_directory() {
+            format!("{self}-{day}")
+        } else {
+            day.to_string()
+        }
+    }
+
+    /// Path to the source file that holds the solution for a day.
+    pub fn bin_path(self, day: Day) -> String {
+        if self.has_own_directory() {
+            format!("./src/bin/{self}/{day}.rs")
+        } else {
+            format!("./src/bin/{day}.rs")
+        }
+    }
+
+    /// Path to the data directory of this event.
+    pub fn data_dir(self) -> String {
+        if self.has_own_directory() {
+            format!("data/{self}")
+        } else {
+            "data".into()
+        }
+    }
+}
+
+const fn parse_year(s: &str) -> Option<Year> {
+    let bytes = s.as_bytes();
+    if bytes.is_empty() {
+        return None;
+    }
+
+    let mut year: u16 = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() || year > 999 {
+            return None;
+        }
+        year = year * 10 + (bytes[i] - b'0') as u16;
+        i += 1;
+    }
+
+    Year::new(year)
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_year(s).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a year of 2015 or later")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Year;
+
+    #[test]
+    fn parses_years() {
+        assert_eq!("2024".parse::<Year>().ok(), Some(Year(2024)));
+        assert!("2014".parse::<Year>().is_err());
+        assert!("20x4".parse::<Year>().is_err());
+        assert!("".parse::<Year>().is_err());
+        assert!("202400".parse::<Year>().is_err());
+    }
+
+    #[test]
+    fn reads_year_of_bins() {
+        assert_eq!(Year::of_bin(Some("2024-13")), Year(2024));
+        assert_eq!(Year::of_bin(Some("13")), Year::configured());
+        assert_eq!(Year::of_bin(Some("advent_of_code")), Year::configured());
+        assert_eq!(Year::of_bin(None), Year::configured());
+    }
+
+    #[test]
+    fn knows_day_count() {
+        assert_eq!(Year(2015).day_count(), 25);
+        assert_eq!(Year(2024).day_count(), 25);
+        assert_eq!(Year(2025).day_count(), 12);
+    }
+}